@@ -1,6 +0,0 @@
-#!/bin/sh
-set -e
-pushd . > /dev/null
-cd dictionaries/english
-./build-dictionary.py
-popd > /dev/null
\ No newline at end of file
@@ -1,9 +1,93 @@
-use std::process::Command;
+use flate2::read::GzDecoder;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use unicode_normalization::UnicodeNormalization;
 
 fn main() {
-    println!("cargo:rerun-if-changed=dictionaries/english/1.txt");
-    Command::new("sh")
-        .args(&["-c", "./build-dictionaries.sh"])
-        .status()
-        .unwrap();
+    println!("cargo:rerun-if-changed=build.rs");
+    let root = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("dictionaries");
+    build_dictionary(&root.join("english"), "english-words.txt.gz");
+    // Spanish words are written without accent marks, as in most Spanish Wordle games, but keep Ñ
+    build_dictionary(&root.join("spanish"), "spanish-words.txt.gz");
+
+    if env::var_os("CARGO_FEATURE_EMBEDDED").is_some() {
+        write_embedded_dictionaries();
+    }
+}
+
+// Splits a gzipped word list, one word per line, into a <size>.txt file for every word size.
+// Sizes count characters rather than bytes, with accented letters composed into a single one.
+fn build_dictionary(directory: &Path, source: &str) {
+    let source = directory.join(source);
+    println!("cargo:rerun-if-changed={}", source.display());
+
+    let file = fs::File::open(&source)
+        .unwrap_or_else(|e| panic!("Couldn't open {}: {}", source.display(), e));
+    let mut by_size: BTreeMap<usize, String> = BTreeMap::new();
+    for line in BufReader::new(GzDecoder::new(file)).lines() {
+        let word: String = line.unwrap().trim().nfc().collect();
+        if word.is_empty() {
+            continue;
+        }
+        let words = by_size.entry(word.chars().count()).or_default();
+        words.push_str(&word);
+        words.push('\n');
+    }
+
+    for (size, words) in by_size {
+        let path = directory.join(format!("{}.txt", size));
+        // Leave up to date files alone, so their timestamps don't change on every build
+        if fs::read_to_string(&path).ok().as_deref() != Some(words.as_str()) {
+            fs::write(&path, words).unwrap();
+        }
+    }
+}
+
+// Generates a table with every dictionaries/<language>/<size>.txt file, so that the word lists can
+// be baked into the binary with include_str!
+fn write_embedded_dictionaries() {
+    let root = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("dictionaries");
+    println!("cargo:rerun-if-changed={}", root.display());
+
+    let mut entries = Vec::new();
+    for language in fs::read_dir(&root).unwrap() {
+        let language = language.unwrap().path();
+        if !language.is_dir() {
+            continue;
+        }
+        println!("cargo:rerun-if-changed={}", language.display());
+        for file in fs::read_dir(&language).unwrap() {
+            let file = file.unwrap().path();
+            let size = match file.extension().and_then(|e| e.to_str()) {
+                Some("txt") => file
+                    .file_stem()
+                    .and_then(|s| s.to_str())
+                    .and_then(|s| s.parse::<usize>().ok()),
+                _ => None,
+            };
+            if let Some(size) = size {
+                println!("cargo:rerun-if-changed={}", file.display());
+                let directory = language.file_name().unwrap().to_str().unwrap().to_string();
+                entries.push((directory, size, file));
+            }
+        }
+    }
+    entries.sort();
+
+    let mut code = String::from("static EMBEDDED_DICTIONARIES: &[(&str, usize, &str)] = &[\n");
+    for (directory, size, file) in entries {
+        code.push_str(&format!(
+            "    ({:?}, {}, include_str!({:?})),\n",
+            directory,
+            size,
+            file.display().to_string()
+        ));
+    }
+    code.push_str("];\n");
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("embedded_dictionaries.rs");
+    fs::write(out, code).unwrap();
 }
@@ -0,0 +1,6 @@
+#[macro_use]
+extern crate error_chain;
+
+mod wordle;
+
+pub use wordle::*;
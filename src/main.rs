@@ -1,86 +1,1337 @@
-#[macro_use]
-extern crate error_chain;
-
+use clap::{Args, Parser, Subcommand};
 use colored::*;
-use std::io::{self, Write};
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use wordle::solver::{rank_openers, OpenerSearch, Solver};
+use wordle::ResultExt;
+use wordle::{
+    AbsurdleGameImpl, CharAndStatus, CharStatus, Dictionary, GameConfig, GameStatus, GuessError,
+    GuessResult, Language, LetterCase, Messages, MultiBoardGame, Palette, RoundResult, Statistics,
+    VecDictionary, WordleGame, WordleGameImpl,
+};
+
+#[derive(Parser)]
+#[command(
+    about = "Play Wordle in the terminal",
+    args_conflicts_with_subcommands = true
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    // Playing is the default, so its options can be given without the subcommand
+    #[command(flatten)]
+    play: PlayArgs,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Play a game (the default)
+    Play(PlayArgs),
+    /// Show the statistics of the games played so far
+    Stats(StatsArgs),
+    /// Rank the dictionary's words as first guesses; slow for big dictionaries
+    BenchOpener(BenchOpenerArgs),
+    /// Get suggestions for a game played elsewhere by entering each guess and its feedback
+    Solve(SolveArgs),
+}
+
+#[derive(Args)]
+struct StatsArgs {
+    /// Language to show the statistics in (en, es)
+    #[arg(long, default_value = "en", value_parser = parse_language)]
+    language: Language,
+}
+
+#[derive(Args)]
+struct SolveArgs {
+    /// Language of the dictionary (en, es)
+    #[arg(long, default_value = "en", value_parser = parse_language)]
+    language: Language,
+
+    /// Number of letters of the word
+    #[arg(long, default_value_t = 5)]
+    word_size: usize,
+
+    /// Word list to solve with instead of the built-in dictionary
+    #[arg(long, value_name = "PATH")]
+    dictionary: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct BenchOpenerArgs {
+    /// Language of the dictionary (en, es)
+    #[arg(long, default_value = "en", value_parser = parse_language)]
+    language: Language,
+
+    /// Number of letters of the words
+    #[arg(long, default_value_t = 5)]
+    word_size: usize,
+
+    /// Word list to rank instead of the built-in dictionary
+    #[arg(long, value_name = "PATH")]
+    dictionary: Option<PathBuf>,
+
+    /// How many of the best openers to show
+    #[arg(long, default_value_t = 10)]
+    top: usize,
+
+    /// Only try this many words, spread over the dictionary, to get an answer sooner
+    #[arg(long)]
+    sample: Option<usize>,
+
+    /// Stop after this many seconds, ranking the words tried so far
+    #[arg(long, value_name = "SECONDS")]
+    time_limit: Option<u64>,
+}
+
+#[derive(Args)]
+struct PlayArgs {
+    /// Suggest possible words after each round
+    #[arg(long)]
+    assist: bool,
+
+    /// Tell how many words are still possible after each round
+    #[arg(long)]
+    show_candidates: bool,
+
+    /// Any revealed hints must be used in subsequent guesses
+    #[arg(long)]
+    hard: bool,
+
+    /// Reject guessing a word that was already guessed instead of using up a turn
+    #[arg(long)]
+    no_repeats: bool,
+
+    /// Accept any guess of the right length made of valid letters, even if it's not a word
+    #[arg(long)]
+    dont_use_dictionary: bool,
+
+    /// Seed used to pick the word, so the same puzzle can be played again
+    #[arg(long)]
+    seed: Option<u64>,
+
+    /// Play today's puzzle, the same for everyone
+    #[arg(long, conflicts_with = "seed")]
+    daily: bool,
+
+    /// Set the word for someone else to guess. Without a value, it's asked for without echoing it
+    #[arg(long, value_name = "WORD", num_args = 0..=1, conflicts_with_all = ["seed", "daily"])]
+    word: Option<Option<String>>,
+
+    /// Print a shareable emoji grid when the game ends
+    #[arg(long)]
+    share: bool,
+
+    /// Save progress to this file after every round, resuming from it if it exists
+    #[arg(long, value_name = "PATH")]
+    save_file: Option<PathBuf>,
+
+    /// Language of the dictionary (en, es)
+    #[arg(long, default_value = "en", value_parser = parse_language)]
+    language: Language,
+
+    /// Number of letters of the word
+    #[arg(long, default_value_t = 5)]
+    word_size: usize,
+
+    /// Word list to play with, one word per line, instead of the built-in dictionary
+    #[arg(long, value_name = "PATH")]
+    dictionary: Option<PathBuf>,
+
+    /// Show letters in lowercase
+    #[arg(long)]
+    lowercase: bool,
+
+    /// Colors for the letter feedback (standard, colorblind)
+    #[arg(long, default_value = "standard")]
+    palette: Palette,
+
+    /// Number of hints available; enter ? instead of a word to reveal a letter
+    #[arg(long, default_value_t = 0)]
+    hints: usize,
+
+    /// Reveal a letter for free once this many guesses have missed the word
+    #[arg(
+        long,
+        value_name = "N",
+        value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..)
+    )]
+    mercy_after: Option<usize>,
+
+    /// Don't show the letters' statuses before each guess
+    #[arg(long)]
+    hide_keyboard: bool,
+
+    /// Only show the letters that have been tried before each guess
+    #[arg(long, conflicts_with = "hide_keyboard")]
+    compact_keyboard: bool,
 
-mod wordle;
-use wordle::{CharAndStatus, CharStatus, Dictionary, GuessResult, RoundResult, WordleGame};
+    /// Mark letters with brackets instead of colors, e.g. for piping (also set by NO_COLOR)
+    #[arg(long)]
+    no_color: bool,
 
-fn colored_char_by_status(cs: &CharAndStatus) -> ColoredString {
-    let CharAndStatus(c, status) = *cs;
-    let c = c.to_string();
-    match status {
-        CharStatus::NotInWord => c.black().on_red(),
-        CharStatus::WrongPosition => c.black().on_yellow(),
-        CharStatus::RightPosition => c.black().on_green(),
-        CharStatus::NotUsed => c.white(),
+    /// Reveal the letters of each guess one at a time, waiting this long between them
+    #[arg(long, value_name = "MILLISECONDS", default_value_t = 0)]
+    reveal_delay_ms: u64,
+
+    /// Show the points scored when the game is won
+    #[arg(long)]
+    score: bool,
+
+    /// Time the game and show how long it took to solve
+    #[arg(long)]
+    timed: bool,
+
+    /// Number of guesses allowed [default: 6, or one per board plus 5 with --boards]
+    #[arg(
+        long,
+        value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..)
+    )]
+    num_guesses: Option<usize>,
+
+    /// Practice with unlimited guesses; games can't be lost, so only wins are recorded
+    #[arg(long)]
+    unlimited: bool,
+
+    /// Play this many boards at once, each guess going to all of them (e.g. 4 for Quordle)
+    #[arg(
+        long,
+        default_value_t = 1,
+        value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..),
+        conflicts_with_all = ["hard", "daily", "word", "save_file", "unlimited", "opener", "hints", "mercy_after"]
+    )]
+    boards: usize,
+
+    /// Play this word as the first guess of every game
+    #[arg(long, value_name = "WORD")]
+    opener: Option<String>,
+
+    /// Play against an adversary that avoids committing to a word for as long as it can
+    #[arg(long, conflicts_with_all = ["hard", "seed", "daily", "word", "save_file", "unlimited", "boards", "opener", "mercy_after", "dont_use_dictionary"])]
+    absurdle: bool,
+}
+
+// The library knows about French too, but there are no word lists for it yet, so the CLI only
+// offers the languages it can play
+fn parse_language(code: &str) -> std::result::Result<Language, String> {
+    match code.parse::<Language>() {
+        Ok(Language::French) => {
+            Err("There are no word lists for French yet (available: en, es)".into())
+        }
+        Ok(language) => Ok(language),
+        Err(_) => Err(format!("Unknown language \"{}\" (available: en, es)", code)),
+    }
+}
+
+enum KeyboardDisplay {
+    Full,
+    // Only letters whose status is known
+    Compact,
+    Hidden,
+}
+
+impl PlayArgs {
+    fn keyboard_display(&self) -> KeyboardDisplay {
+        if self.hide_keyboard {
+            KeyboardDisplay::Hidden
+        } else if self.compact_keyboard {
+            KeyboardDisplay::Compact
+        } else {
+            KeyboardDisplay::Full
+        }
+    }
+
+    fn messages(&self) -> Messages {
+        Messages::new(self.language)
+    }
+
+    fn letter_case(&self) -> LetterCase {
+        if self.lowercase {
+            LetterCase::Lower
+        } else {
+            LetterCase::Upper
+        }
+    }
+
+    fn config(&self) -> GameConfig {
+        GameConfig {
+            word_size: self.word_size,
+            num_guesses: self.num_guesses,
+            unlimited_guesses: self.unlimited,
+            hard_mode: self.hard,
+            reject_repeat_guesses: self.no_repeats,
+            allow_any_word: self.dont_use_dictionary,
+            hints: self.hints,
+            mercy_after: self.mercy_after,
+            language: self.language,
+            seed: self.seed,
+            dictionary: self.dictionary.clone(),
+        }
+    }
+
+    // See https://no-color.org
+    fn plain_output(&self) -> bool {
+        self.no_color || std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty())
     }
 }
 
-fn print_chars_with_status(chars_status: &[CharAndStatus]) {
-    let colored_string = chars_status
+fn render_char(cs: &CharAndStatus, cli: &PlayArgs) -> String {
+    let c = cs.display_char(cli.letter_case());
+    if cli.plain_output() {
+        return match cs.1 {
+            CharStatus::RightPosition => format!("[{}]", c),
+            CharStatus::WrongPosition => format!("({})", c),
+            CharStatus::NotInWord => format!(" {} ", c),
+            CharStatus::NotUsed => format!("_{}_", c),
+        };
+    }
+    match cli.palette.background(cs.1) {
+        Some(color) => c.black().on_color(color).to_string(),
+        None => c.white().to_string(),
+    }
+}
+
+fn render_chars(chars_status: &[CharAndStatus], cli: &PlayArgs) -> String {
+    chars_status
         .iter()
-        .map(colored_char_by_status)
-        .map(|cs| cs.to_string())
+        .map(|cs| render_char(cs, cli))
         .collect::<Vec<String>>()
-        .join(" ");
-    println!("{}", colored_string);
+        .join(" ")
 }
 
-fn print_guess_result(result: &GuessResult) {
-    print_chars_with_status(&result.chars_result);
+// Writes the letters one at a time, calling `pause` after each one when there's a delay
+fn reveal_chars(
+    chars_status: &[CharAndStatus],
+    cli: &PlayArgs,
+    delay: Duration,
+    out: &mut dyn Write,
+    pause: &mut dyn FnMut(Duration),
+) -> io::Result<()> {
+    for (i, cs) in chars_status.iter().enumerate() {
+        if i > 0 {
+            write!(out, " ")?;
+        }
+        write!(out, "{}", render_char(cs, cli))?;
+        if !delay.is_zero() {
+            out.flush()?;
+            pause(delay);
+        }
+    }
+    writeln!(out)
 }
 
-fn game_loop(game: &mut dyn WordleGame) -> wordle::Result<()> {
+fn print_chars_with_status(chars_status: &[CharAndStatus], cli: &PlayArgs, delay: Duration) {
+    let _ = reveal_chars(
+        chars_status,
+        cli,
+        delay,
+        &mut io::stdout(),
+        &mut thread::sleep,
+    );
+}
+
+fn keyboard_letters(chars_status: &[CharAndStatus], cli: &PlayArgs) -> Vec<CharAndStatus> {
+    match cli.keyboard_display() {
+        KeyboardDisplay::Full => chars_status.to_vec(),
+        KeyboardDisplay::Compact => chars_status
+            .iter()
+            .filter(|cs| cs.1 != CharStatus::NotUsed)
+            .cloned()
+            .collect(),
+        KeyboardDisplay::Hidden => Vec::new(),
+    }
+}
+
+fn print_keyboard(chars_status: &[CharAndStatus], cli: &PlayArgs) {
+    let letters = keyboard_letters(chars_status, cli);
+    if !letters.is_empty() {
+        print!("{}", cli.messages().available_letters());
+        print_chars_with_status(&letters, cli, Duration::ZERO);
+    }
+}
+
+fn print_guess_result(result: &GuessResult, cli: &PlayArgs) {
+    let delay = Duration::from_millis(cli.reveal_delay_ms);
+    print_chars_with_status(&result.chars_result, cli, delay);
+}
+
+// The whole board, one row per guess, so a saved game can be looked over again
+#[cfg(any(feature = "serde", test))]
+fn render_replay(status: &GameStatus, cli: &PlayArgs) -> String {
+    status
+        .guesses
+        .iter()
+        .map(|guess| render_chars(&guess.chars_result, cli))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+// An empty row for each attempt left, to show under the guesses like the grid in the real game.
+// Unlimited games have no attempts to show.
+fn empty_rows(status: &GameStatus, cli: &PlayArgs) -> Vec<String> {
+    let word_size = status
+        .guesses
+        .first()
+        .map_or(0, |guess| guess.chars_result.len());
+    let empty_cell = if cli.plain_output() { " _ " } else { "_" };
+    let empty_row = vec![empty_cell; word_size].join(" ");
+    let remaining = status.guesses_remaining().unwrap_or(0);
+    vec![empty_row; remaining]
+}
+
+// Every guess so far, with the latest one revealed like any other guess result
+fn print_board(status: &GameStatus, cli: &PlayArgs) {
+    let (last, previous) = match status.guesses.split_last() {
+        Some(guesses) => guesses,
+        None => return,
+    };
+    for guess in previous {
+        println!("{}", render_chars(&guess.chars_result, cli));
+    }
+    print_guess_result(last, cli);
+    for row in empty_rows(status, cli) {
+        println!("{}", row);
+    }
+}
+
+// The solver scores candidates the same way the game does, and the constraints add whatever the
+// hints revealed
+fn remaining_candidates(solver: &Solver, status: &GameStatus) -> usize {
+    let constraints = status.constraints();
+    solver
+        .candidates(&status.guesses)
+        .iter()
+        .filter(|word| constraints.allows(word))
+        .count()
+}
+
+fn print_suggestions(solver: &Solver, guesses: &[GuessResult], messages: &Messages) {
+    let candidates = solver.candidates(guesses);
+    let shown: Vec<&str> = candidates.iter().take(10).map(|w| w.as_str()).collect();
+    let mut words = shown.join(", ");
+    if candidates.len() > shown.len() {
+        words += ", ...";
+    }
+    println!("{}", messages.candidates(candidates.len(), &words));
+    if let Some(best) = solver.best_guess(guesses) {
+        println!("{}", messages.suggested_guess(&best));
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    format!("{}:{:02}", secs / 60, secs % 60)
+}
+
+fn print_final_result(game: &impl WordleGame, cli: &PlayArgs) {
+    let status = game.status();
+    if cli.share {
+        println!("{}", status.emoji_grid());
+        if cli.timed {
+            println!("⏱️ {}", format_duration(game.elapsed()));
+        }
+    } else {
+        print_board(status, cli);
+        if cli.timed {
+            let elapsed = format_duration(game.elapsed());
+            println!("{}", cli.messages().time(&elapsed));
+        }
+    }
+}
+
+fn forget_saved_game(cli: &PlayArgs) {
+    if let Some(path) = &cli.save_file {
+        let _ = fs::remove_file(path);
+    }
+}
+
+// Games and statistics are only kept between runs by builds with the serde feature
+#[cfg(feature = "serde")]
+fn stats_path() -> Option<PathBuf> {
+    let home = std::env::var_os("HOME").or_else(|| std::env::var_os("USERPROFILE"))?;
+    Some(Path::new(&home).join(".wordle").join("stats.json"))
+}
+
+#[cfg(feature = "serde")]
+fn load_stats() -> wordle::Result<Statistics> {
+    match stats_path() {
+        Some(path) => Statistics::load(&path),
+        None => Ok(Statistics::new()),
+    }
+}
+
+#[cfg(not(feature = "serde"))]
+fn load_stats() -> wordle::Result<Statistics> {
+    Ok(Statistics::new())
+}
+
+#[cfg(feature = "serde")]
+fn save_stats(stats: &Statistics) -> wordle::Result<()> {
+    if let Some(path) = stats_path() {
+        stats.save(&path)?;
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "serde"))]
+fn save_stats(_stats: &Statistics) -> wordle::Result<()> {
+    Ok(())
+}
+
+#[cfg(feature = "serde")]
+fn save_game(game: &wordle::WordleGameImpl, cli: &PlayArgs) -> wordle::Result<()> {
+    if let Some(path) = &cli.save_file {
+        fs::write(path, game.save()?)?;
+    }
+    Ok(())
+}
+
+// run_game turns --save-file down before playing, so there's never anything to save
+#[cfg(not(feature = "serde"))]
+fn save_game(_game: &wordle::WordleGameImpl, _cli: &PlayArgs) -> wordle::Result<()> {
+    Ok(())
+}
+
+#[cfg(not(feature = "serde"))]
+const NO_SAVE_SUPPORT: &str = "This build can't save games, it needs the serde feature";
+
+fn game_loop<G: WordleGame>(
+    game: &mut G,
+    save: impl Fn(&G) -> wordle::Result<()>,
+    solver: Option<&Solver>,
+    stats: &mut Statistics,
+    cli: &PlayArgs,
+    input: &mut dyn BufRead,
+) -> wordle::Result<()> {
+    let messages = cli.messages();
     loop {
-        print!("Available letters: ");
-        print_chars_with_status(&game.chars_status());
+        print_keyboard(&game.chars_status(), cli);
+        if let Some(remaining) = game.guesses_remaining() {
+            println!("{}", messages.guesses_left(remaining));
+        }
+        if game.status().max_hints > 0 {
+            println!("{}", messages.hints_left(game.status().hints_remaining()));
+        }
 
-        print!("Enter a word!: ");
-        let _ = io::stdout().flush();
-        let word: String = {
-            let mut word = String::new();
-            io::stdin().read_line(&mut word)?;
-            word.trim().into()
+        let line = match prompt_line(messages.enter_word(), input)? {
+            Some(line) => line,
+            None => {
+                println!();
+                println!("{}", messages.goodbye());
+                return Ok(());
+            }
         };
 
+        if line.trim() == "?" {
+            match game.use_hint() {
+                Ok((pos, c)) => println!("{}", messages.hint(pos, c)),
+                Err(e) => eprintln!("{}", messages.error(&messages.hint_error(&e))),
+            }
+            save(game)?;
+            continue;
+        }
+
+        let word = match normalize_guess(&line, &messages) {
+            Ok(word) => word,
+            Err(e) => {
+                eprintln!("{}", messages.error(&e.to_string()));
+                continue;
+            }
+        };
         let round_result = game.guess_word(&word);
-        match round_result {
-            RoundResult::Error(s) => eprintln!("Error: {}", s),
-            RoundResult::Won(ref status, word) => {
-                print_guess_result(status.guesses.last().unwrap());
-                println!("Won! The word was {}", word);
-                break;
+        stats.record(&round_result);
+        let outcome = match round_result {
+            RoundResult::Error(e) => {
+                eprintln!("{}", messages.error(&messages.guess_error(&e)));
+                None
             }
-            RoundResult::Lost(ref status, word) => {
-                print_guess_result(status.guesses.last().unwrap());
-                println!("Lost :( The word was {}", word);
-                break;
+            RoundResult::Won(_, word) => Some(messages.won(&word)),
+            RoundResult::Lost(_, word) => Some(messages.lost(&word)),
+            RoundResult::Continue(status) => {
+                print_board(status, cli);
+                if cli.mercy_after == Some(status.guesses.len()) {
+                    if let Some(&(pos, c)) = status.mercy_hints.first() {
+                        println!("{}", messages.mercy_hint(pos, c));
+                    }
+                }
+                if let Some(solver) = solver {
+                    if cli.show_candidates {
+                        let remaining = remaining_candidates(solver, status);
+                        println!("{}", messages.possible_words(remaining));
+                    }
+                    if cli.assist {
+                        print_suggestions(solver, &status.guesses, &messages);
+                    }
+                }
+                println!("{}", messages.moving_on());
+                None
             }
-            RoundResult::Continue(ref status) => {
-                print_guess_result(status.guesses.last().unwrap());
-                println!("Moving on...");
+        };
+
+        if let Some(outcome) = outcome {
+            finish_game(game, &outcome, cli);
+            break;
+        }
+
+        save(game)?;
+    }
+
+    Ok(())
+}
+
+fn finish_game(game: &impl WordleGame, outcome: &str, cli: &PlayArgs) {
+    print_final_result(game, cli);
+    println!("{}", outcome);
+    if cli.score {
+        println!("{}", cli.messages().score(game.score()));
+    }
+    forget_saved_game(cli);
+}
+
+// Plays the opener of a game that hasn't started yet. Returns whether that already ended it.
+fn play_opener(
+    game: &mut WordleGameImpl,
+    opener: &str,
+    stats: &mut Statistics,
+    cli: &PlayArgs,
+) -> wordle::Result<bool> {
+    if !game.status().guesses.is_empty() {
+        return Ok(false);
+    }
+    let messages = cli.messages();
+    game.auto_guess(opener)
+        .map_err(|e| messages.invalid_opener(opener, &messages.guess_error(&e)))?;
+
+    // A game the opener ends gets its board shown with the result instead
+    let outcome = match game.outcome() {
+        Some(outcome) => outcome,
+        None => {
+            print_board(game.status(), cli);
+            return Ok(false);
+        }
+    };
+    let message = if outcome.won {
+        stats.record(&RoundResult::Won(game.status(), outcome.word.clone()));
+        cli.messages().won(&outcome.word)
+    } else {
+        stats.record(&RoundResult::Lost(game.status(), outcome.word.clone()));
+        cli.messages().lost(&outcome.word)
+    };
+    finish_game(game, &message, cli);
+    Ok(true)
+}
+
+// Pasted words often come with stray spaces (including non-breaking ones), which are dropped.
+// Anything else that isn't a letter, like punctuation or quotes, is rejected.
+fn normalize_guess(line: &str, messages: &Messages) -> wordle::Result<String> {
+    let word: String = line.chars().filter(|c| !c.is_whitespace()).collect();
+    let mut invalid: Vec<char> = Vec::new();
+    for c in word.chars().filter(|c| !c.is_alphabetic()) {
+        if !invalid.contains(&c) {
+            invalid.push(c);
+        }
+    }
+    if !invalid.is_empty() {
+        return Err(messages.only_letters(&invalid).into());
+    }
+    Ok(word)
+}
+
+fn ask_yes_no(question: &str, input: &mut dyn BufRead) -> wordle::Result<bool> {
+    let answer = prompt_line(&format!("{} [y/N]: ", question), input)?;
+    Ok(answer.is_some_and(|answer| matches!(answer.trim().to_lowercase().as_str(), "y" | "yes")))
+}
+
+// The line read, or None once the input is over (e.g. on Ctrl-D). Lines are read as the terminal
+// hands them over, so editing beyond what it offers (or a wrapper like rlwrap adds, e.g. history
+// with the arrow keys) is up to it, and Ctrl-C interrupts the game as usual.
+fn prompt_line(prompt: &str, input: &mut dyn BufRead) -> wordle::Result<Option<String>> {
+    print!("{}", prompt);
+    let _ = io::stdout().flush();
+    let mut line = String::new();
+    if input.read_line(&mut line)? == 0 {
+        return Ok(None);
+    }
+    Ok(Some(line))
+}
+
+fn read_hidden_word() -> wordle::Result<String> {
+    let word = rpassword::prompt_password("Word to guess (not shown): ")
+        .chain_err(|| "Couldn't read the word from the terminal")?;
+    Ok(word.trim().into())
+}
+
+#[cfg(not(feature = "serde"))]
+fn load_game(
+    _path: &Path,
+    _dict: Box<dyn Dictionary>,
+    _cli: &PlayArgs,
+) -> wordle::Result<wordle::WordleGameImpl> {
+    Err(NO_SAVE_SUPPORT.into())
+}
+
+#[cfg(feature = "serde")]
+fn load_game(
+    path: &Path,
+    dict: Box<dyn Dictionary>,
+    cli: &PlayArgs,
+) -> wordle::Result<wordle::WordleGameImpl> {
+    let saved = fs::read_to_string(path)?;
+    let game = wordle::WordleGameImpl::load(dict, &saved)?;
+    let messages = cli.messages();
+    println!("{}", messages.resuming_game(&path.display().to_string()));
+    if !game.status().guesses.is_empty() {
+        println!("{}", render_replay(game.status(), cli));
+    }
+    Ok(game)
+}
+
+// Plays with whatever dictionary it's given, reading guesses from `input`
+fn run_game(
+    dict: Box<dyn Dictionary>,
+    config: &GameConfig,
+    cli: &PlayArgs,
+    input: &mut dyn BufRead,
+    stats: &mut Statistics,
+) -> wordle::Result<()> {
+    #[cfg(not(feature = "serde"))]
+    if cli.save_file.is_some() {
+        return Err(NO_SAVE_SUPPORT.into());
+    }
+
+    let word_size = dict.word_size().unwrap_or(config.word_size);
+    let solver = if cli.assist || cli.show_candidates {
+        let words = dict.words().map(|w| w.to_string());
+        Some(Solver::new(Box::new(VecDictionary::new(words, word_size)?)))
+    } else {
+        None
+    };
+
+    if cli.boards > 1 {
+        return play_boards(dict, config, cli, input);
+    }
+    if cli.absurdle {
+        let mut game = AbsurdleGameImpl::new(dict, config.max_guesses());
+        return game_loop(&mut game, |_| Ok(()), solver.as_ref(), stats, cli, input);
+    }
+
+    let mut game = match &cli.save_file {
+        Some(path) if path.exists() => load_game(path, dict, cli)?,
+        _ => {
+            let word = if let Some(word) = &cli.word {
+                match word {
+                    Some(word) => word.clone(),
+                    None => read_hidden_word()?,
+                }
+            } else if cli.daily {
+                let today = chrono::Local::now().date_naive();
+                wordle::daily_word(dict.as_ref(), word_size, today)?
+            } else if let Some(seed) = config.seed {
+                dict.get_random_word_seeded(word_size, seed)?
+            } else {
+                dict.get_random_word(word_size)?
+            };
+            config.game_builder(dict).word(&word).build()?
+        }
+    };
+    loop {
+        let over = match &cli.opener {
+            Some(opener) => play_opener(&mut game, opener, stats, cli)?,
+            None => false,
+        };
+        if !over {
+            let save = |game: &wordle::WordleGameImpl| save_game(game, cli);
+            game_loop(&mut game, save, solver.as_ref(), stats, cli, input)?;
+        }
+
+        let lost = game.outcome().is_some_and(|outcome| !outcome.won);
+        if !lost || !ask_yes_no(cli.messages().play_again(), input)? {
+            break;
+        }
+        let word = game.dictionary().get_random_word(word_size)?;
+        game.reset(&word)?;
+    }
+    Ok(())
+}
+
+// Every board gets the same options and a handle to the same word list
+fn new_boards(
+    dict: Box<dyn Dictionary>,
+    config: &GameConfig,
+    num_boards: usize,
+) -> wordle::Result<MultiBoardGame> {
+    let dict: Arc<dyn Dictionary> = Arc::from(dict);
+    let word_size = dict.word_size().unwrap_or(config.word_size);
+    let max_guesses = config
+        .num_guesses
+        .unwrap_or_else(|| MultiBoardGame::default_max_guesses(num_boards));
+    let mut boards = Vec::new();
+    for n in 0..num_boards {
+        let word = match config.seed {
+            Some(seed) => dict.get_random_word_seeded(word_size, seed.wrapping_add(n as u64))?,
+            None => dict.get_random_word(word_size)?,
+        };
+        let board = config
+            .game_builder(Box::new(Arc::clone(&dict)))
+            .word(&word)
+            .max_guesses(max_guesses)
+            .build()?;
+        boards.push(board);
+    }
+    MultiBoardGame::new(boards)
+}
+
+// Games with several boards aren't recorded in the statistics, which are about single words
+fn play_boards(
+    dict: Box<dyn Dictionary>,
+    config: &GameConfig,
+    cli: &PlayArgs,
+    input: &mut dyn BufRead,
+) -> wordle::Result<()> {
+    let mut game = new_boards(dict, config, cli.boards)?;
+    let messages = cli.messages();
+
+    while !game.is_over() {
+        if let Some(remaining) = game.guesses_remaining() {
+            println!("{}", messages.guesses_left(remaining));
+        }
+        let line = match prompt_line(messages.enter_word(), input)? {
+            Some(line) => line,
+            None => {
+                println!();
+                println!("{}", messages.goodbye());
+                return Ok(());
+            }
+        };
+
+        let word = match normalize_guess(&line, &messages) {
+            Ok(word) => word,
+            Err(e) => {
+                eprintln!("{}", messages.error(&e.to_string()));
+                continue;
             }
+        };
+        let results = match game.guess_word(&word) {
+            Ok(results) => results,
+            Err(e) => {
+                eprintln!("{}", messages.error(&messages.guess_error(&e)));
+                continue;
+            }
+        };
+        for (n, result) in results.iter().enumerate() {
+            print!("{}", messages.board(n + 1));
+            match result {
+                Some(result) => print_guess_result(result, cli),
+                None => println!("{}", messages.board_solved()),
+            }
+        }
+    }
+
+    let words: Vec<String> = game
+        .boards()
+        .iter()
+        .filter_map(|board| board.outcome())
+        .map(|outcome| outcome.word)
+        .collect();
+    if game.is_won() {
+        println!("{}", messages.won_all(&words.join(", ")));
+    } else {
+        println!("{}", messages.lost_all(&words.join(", ")));
+    }
+    Ok(())
+}
+
+fn show_stats(args: &StatsArgs) -> wordle::Result<()> {
+    let stats = load_stats()?;
+    let messages = Messages::new(args.language);
+    if stats.games_played == 0 {
+        println!("{}", messages.no_games_played());
+    } else {
+        print!("{}", messages.statistics(&stats));
+    }
+    Ok(())
+}
+
+fn bench_opener(args: &BenchOpenerArgs) -> wordle::Result<()> {
+    let config = GameConfig {
+        word_size: args.word_size,
+        language: args.language,
+        dictionary: args.dictionary.clone(),
+        ..GameConfig::default()
+    };
+    let dict = config.load_dictionary()?;
+    let search = OpenerSearch {
+        sample: args.sample,
+        time_limit: args.time_limit.map(Duration::from_secs),
+    };
+    let ranking = rank_openers(&dict, &search, |done, total| {
+        if done % 100 == 0 || done == total {
+            eprint!("\rTried {}/{} words", done, total);
         }
+    });
+    eprintln!();
+    for (word, entropy) in ranking.iter().take(args.top) {
+        println!("{} {:.3} bits", word, entropy);
     }
+    Ok(())
+}
 
+fn solve(args: &SolveArgs) -> wordle::Result<()> {
+    let config = GameConfig {
+        word_size: args.word_size,
+        language: args.language,
+        dictionary: args.dictionary.clone(),
+        ..GameConfig::default()
+    };
+    let solver = Solver::new(Box::new(config.load_dictionary()?));
+    let messages = Messages::new(args.language);
+    println!("{}", messages.solve_instructions());
+    solve_loop(&solver, args.word_size, &messages, &mut io::stdin().lock())?;
     Ok(())
 }
 
+// Returns the words still possible when the input ends or the word is found. Feedback that can't
+// be read, or that no word could have given, is reported and otherwise ignored.
+fn solve_loop(
+    solver: &Solver,
+    word_size: usize,
+    messages: &Messages,
+    input: &mut dyn BufRead,
+) -> wordle::Result<Vec<String>> {
+    let mut guesses: Vec<GuessResult> = Vec::new();
+    let mut candidates = solver.candidates(&guesses);
+    loop {
+        let line = match prompt_line(messages.enter_feedback(), input)? {
+            Some(line) => line,
+            None => {
+                println!();
+                break;
+            }
+        };
+        let guess = match parse_feedback(&line, word_size, messages) {
+            Ok(guess) => guess,
+            Err(e) => {
+                eprintln!("{}", messages.error(&e.to_string()));
+                continue;
+            }
+        };
+        if guess.is_won() {
+            println!("{}", messages.solved());
+            return Ok(vec![guess.word().into()]);
+        }
+
+        guesses.push(guess);
+        let remaining = solver.candidates(&guesses);
+        if remaining.is_empty() {
+            eprintln!("{}", messages.error(messages.no_word_fits()));
+            guesses.pop();
+            continue;
+        }
+        candidates = remaining;
+        if let [word] = candidates.as_slice() {
+            println!("{}", messages.word_must_be(word));
+            break;
+        }
+        print_suggestions(solver, &guesses, messages);
+    }
+    Ok(candidates)
+}
+
+fn parse_feedback(
+    line: &str,
+    word_size: usize,
+    messages: &Messages,
+) -> wordle::Result<GuessResult> {
+    let (word, pattern) = match line.split_whitespace().collect::<Vec<&str>>().as_slice() {
+        [word, pattern] => (*word, *pattern),
+        _ => return Err(messages.feedback_expected().into()),
+    };
+    let got = word.chars().count();
+    if got != word_size {
+        let error = GuessError::WrongLength {
+            word: word.into(),
+            expected: word_size,
+            got,
+        };
+        return Err(messages.guess_error(&error).into());
+    }
+    GuessResult::from_pattern(word, pattern)
+}
+
 fn do_main() -> wordle::Result<()> {
-    let word_size = 5;
-    let dict = wordle::EnglishDictionary::new(word_size)?;
-    let word = dict.get_random_word(word_size)?;
-    // let word = "silos";
-    // println!("Word is: {}", word);
-    let mut game = wordle::WordleGameImpl::new(Box::new(dict), &word, 6)?;
-    game_loop(&mut game)
+    let parsed = Cli::parse();
+    let cli = match parsed.command {
+        Some(Command::Stats(args)) => return show_stats(&args),
+        Some(Command::BenchOpener(args)) => return bench_opener(&args),
+        Some(Command::Solve(args)) => return solve(&args),
+        Some(Command::Play(play)) => play,
+        None => parsed.play,
+    };
+    let config = cli.config();
+    let dict = config.load_dictionary()?;
+
+    let mut stats = load_stats()?;
+    run_game(
+        Box::new(dict),
+        &config,
+        &cli,
+        &mut io::stdin().lock(),
+        &mut stats,
+    )?;
+
+    println!();
+    print!("{}", cli.messages().statistics(&stats));
+    save_stats(&stats)
 }
 
+// The library logs which dictionaries it loads and how guesses are scored through the `log`
+// crate. Nothing is printed unless a logger is installed first thing here, e.g. with
+// `env_logger::init()` after adding `env_logger` as a dependency, and then running with
+// `RUST_LOG=wordle=debug` (or `trace`, which also gives away the word to guess).
 fn main() {
     match do_main() {
         Ok(_) => {}
-        Err(e) => eprintln!("{}", e),
+        Err(e) => {
+            let causes: Vec<String> = e.iter().map(|cause| cause.to_string()).collect();
+            eprintln!("{}", causes.join(": "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn play(args: &[&str], input: &str) -> Statistics {
+        let cli = Cli::parse_from([&["wordle"], args].concat()).play;
+        let words = vec!["sound".into(), "wrong".into(), "boost".into()];
+        let dict = VecDictionary::new(words, 5).unwrap();
+        let mut stats = Statistics::new();
+        run_game(
+            Box::new(dict),
+            &cli.config(),
+            &cli,
+            &mut Cursor::new(input.to_string()),
+            &mut stats,
+        )
+        .unwrap();
+        stats
+    }
+
+    #[test]
+    fn plays_game_with_injected_dictionary() {
+        let stats = play(&["--word", "sound"], "wrong\nbogus\nsound\n");
+        assert_eq!(1, stats.games_won);
+        assert_eq!(vec![0, 1], stats.distribution);
+    }
+
+    #[test]
+    fn repeated_guesses_dont_count_with_no_repeats() {
+        let stats = play(
+            &["--word", "sound", "--no-repeats"],
+            "wrong\nwrong\nsound\n",
+        );
+        assert_eq!(vec![0, 1], stats.distribution);
+    }
+
+    #[test]
+    fn accepts_non_words_without_dictionary() {
+        let stats = play(
+            &["--word", "sound", "--dont-use-dictionary"],
+            "dunso\nsound\n",
+        );
+        assert_eq!(vec![0, 1], stats.distribution);
+    }
+
+    #[test]
+    fn solves_from_entered_feedback() {
+        let words = ["sound", "wrong", "boost", "bound", "found"].map(String::from);
+        let solver = Solver::new(Box::new(VecDictionary::new(words, 5).unwrap()));
+        let messages = Messages::default();
+        // Feedback no word fits and codes that can't be read are skipped
+        let input = "crane GGGGB\nbound\nbound BGGXG\nsoun BGGG\nbound BGGGG\nfound BGGGG\n";
+        let candidates = solve_loop(&solver, 5, &messages, &mut Cursor::new(input)).unwrap();
+        assert_eq!(vec!["SOUND"], candidates);
+
+        let candidates =
+            solve_loop(&solver, 5, &messages, &mut Cursor::new("wrong bbygb\n")).unwrap();
+        assert_eq!(vec!["BOUND", "FOUND", "SOUND"], candidates);
+        let candidates =
+            solve_loop(&solver, 5, &messages, &mut Cursor::new("boost ggggg\n")).unwrap();
+        assert_eq!(vec!["BOOST"], candidates);
+    }
+
+    #[test]
+    fn plays_the_opener_first() {
+        let stats = play(&["--word", "sound", "--opener", "wrong"], "sound\n");
+        assert_eq!(vec![0, 1], stats.distribution);
+
+        let stats = play(&["--word", "sound", "--opener", "sound"], "");
+        assert_eq!((1, 1), (stats.games_played, stats.games_won));
+    }
+
+    #[test]
+    fn offers_new_game_after_loss() {
+        let input = "wrong\n".repeat(6) + "y\n" + &"boost\nsound\nwrong\n".repeat(2);
+        let stats = play(&["--word", "boost", "--assist"], &input);
+        assert_eq!(2, stats.games_played);
+        assert_eq!(1, stats.games_won);
+    }
+
+    #[test]
+    fn unlimited_games_are_not_lost() {
+        let input = "wrong\n".repeat(10) + "sound\n";
+        let stats = play(&["--word", "sound", "--unlimited"], &input);
+        assert_eq!((1, 1), (stats.games_played, stats.games_won));
+        assert_eq!(11, stats.distribution.len());
+    }
+
+    #[test]
+    fn normalizes_guesses() {
+        let messages = Messages::default();
+        assert_eq!("sound", normalize_guess(" sound ", &messages).unwrap());
+        assert_eq!("sound", normalize_guess("sound\t", &messages).unwrap());
+        assert_eq!(
+            "sound",
+            normalize_guess("\u{a0}sou nd\n", &messages).unwrap()
+        );
+        assert_eq!("", normalize_guess("\n", &messages).unwrap());
+
+        let e = normalize_guess("sound’", &messages).unwrap_err();
+        assert_eq!("Only letters are allowed, found ’", e.to_string());
+        assert!(normalize_guess("sound!", &messages).is_err());
+        let e = normalize_guess("sound!", &Messages::new(Language::Spanish)).unwrap_err();
+        assert_eq!("Solo se permiten letras, se encontró !", e.to_string());
+    }
+
+    #[test]
+    fn renders_plain_text_without_colors() {
+        let cli = Cli::parse_from(["wordle", "--no-color"]).play;
+        let result = GuessResult::from_pattern("sound", "GYBBB").unwrap();
+        let mut chars = result.chars_result.clone();
+        chars[4].1 = CharStatus::NotUsed;
+
+        let rendered = render_chars(&chars, &cli);
+        assert_eq!("[S] (O)  U   N  _D_", rendered);
+        assert!(!rendered.contains('\x1b'));
+    }
+
+    #[test]
+    fn renders_replay_of_every_guess() {
+        let cli = Cli::parse_from(["wordle", "--no-color"]).play;
+        let status = GameStatus {
+            guesses: vec![
+                GuessResult::from_pattern("wrong", "BBYGB").unwrap(),
+                GuessResult::from_pattern("sound", "GGGGG").unwrap(),
+            ],
+            max_guesses: Some(6),
+            hints: Vec::new(),
+            max_hints: 0,
+            mercy_hints: Vec::new(),
+        };
+        assert_eq!(
+            " W   R  (O) [N]  G \n[S] [O] [U] [N] [D]",
+            render_replay(&status, &cli)
+        );
+    }
+
+    #[test]
+    fn reveals_letters_one_at_a_time() {
+        let cli = Cli::parse_from(["wordle", "--no-color", "--reveal-delay-ms", "250"]).play;
+        let result = GuessResult::from_pattern("wrong", "BBYGB").unwrap();
+        let delay = Duration::from_millis(cli.reveal_delay_ms);
+
+        let mut out = Vec::new();
+        let mut pauses = Vec::new();
+        reveal_chars(&result.chars_result, &cli, delay, &mut out, &mut |d| {
+            pauses.push(d)
+        })
+        .unwrap();
+        assert_eq!(" W   R  (O) [N]  G \n", String::from_utf8(out).unwrap());
+        assert_eq!(vec![Duration::from_millis(250); 5], pauses);
+
+        let mut pauses = 0;
+        reveal_chars(
+            &result.chars_result,
+            &cli,
+            Duration::ZERO,
+            &mut Vec::new(),
+            &mut |_| pauses += 1,
+        )
+        .unwrap();
+        assert_eq!(0, pauses);
+    }
+
+    #[test]
+    fn renders_empty_rows_for_attempts_left() {
+        let cli = Cli::parse_from(["wordle", "--no-color"]).play;
+        let mut status = GameStatus {
+            guesses: vec![GuessResult::from_pattern("wrong", "BBYGB").unwrap()],
+            max_guesses: Some(3),
+            hints: Vec::new(),
+            max_hints: 0,
+            mercy_hints: Vec::new(),
+        };
+        assert_eq!(vec![" _   _   _   _   _ "; 2], empty_rows(&status, &cli));
+
+        status.max_guesses = None;
+        assert!(empty_rows(&status, &cli).is_empty());
+    }
+
+    #[test]
+    fn counts_remaining_candidates() {
+        let words = ["sound", "wrong", "boost", "bound", "round"].map(String::from);
+        let solver = Solver::new(Box::new(VecDictionary::new(words, 5).unwrap()));
+        let mut status = GameStatus {
+            guesses: vec![GuessResult::from_pattern("wrong", "BBYGB").unwrap()],
+            max_guesses: Some(6),
+            hints: Vec::new(),
+            max_hints: 0,
+            mercy_hints: Vec::new(),
+        };
+        assert_eq!(2, remaining_candidates(&solver, &status));
+        status.hints.push((0, 'S'));
+        assert_eq!(1, remaining_candidates(&solver, &status));
+
+        let stats = play(&["--word", "sound", "--show-candidates"], "wrong\nsound\n");
+        assert_eq!(1, stats.games_won);
+    }
+
+    #[test]
+    fn limits_the_number_of_guesses() {
+        let stats = play(&["--word", "sound", "--num-guesses", "1"], "wrong\n");
+        assert_eq!((1, 0), (stats.games_played, stats.games_won));
+        assert!(Cli::try_parse_from(["wordle", "--num-guesses", "0"]).is_err());
+    }
+
+    #[test]
+    fn filters_keyboard_letters() {
+        let mut chars = GuessResult::from_pattern("sound", "GYBBB")
+            .unwrap()
+            .chars_result;
+        chars[4].1 = CharStatus::NotUsed;
+        let letters = |args: &[&str]| {
+            let cli = Cli::parse_from([&["wordle"], args].concat()).play;
+            let letters = keyboard_letters(&chars, &cli);
+            letters.iter().map(|cs| cs.0).collect::<String>()
+        };
+
+        assert_eq!("SOUND", letters(&[]));
+        assert_eq!("SOUN", letters(&["--compact-keyboard"]));
+        assert_eq!("", letters(&["--hide-keyboard"]));
+    }
+
+    #[test]
+    fn end_of_input_ends_the_game() {
+        assert_eq!(None, prompt_line("", &mut Cursor::new("")).unwrap());
+        assert_eq!(
+            Some("\n".to_string()),
+            prompt_line("", &mut Cursor::new("\n")).unwrap()
+        );
+
+        // Unfinished games aren't recorded
+        let stats = play(&["--word", "sound"], "wrong\n");
+        assert_eq!(0, stats.games_played);
+        let stats = play(&["--boards", "2"], "");
+        assert_eq!(0, stats.games_played);
+    }
+
+    #[test]
+    fn maps_arguments_into_config() {
+        let cli = Cli::parse_from(["wordle", "--word-size", "6", "--hard", "--seed", "3"]).play;
+        let config = cli.config();
+        assert_eq!(
+            GameConfig::new()
+                .with_word_size(6)
+                .with_hard_mode(true)
+                .with_seed(3),
+            config
+        );
+    }
+
+    #[test]
+    fn parses_subcommands() {
+        let cli = Cli::parse_from(["wordle", "stats"]);
+        assert!(matches!(cli.command, Some(Command::Stats(_))));
+        let cli = Cli::parse_from(["wordle", "stats", "--language", "es"]);
+        assert!(matches!(
+            cli.command,
+            Some(Command::Stats(StatsArgs {
+                language: Language::Spanish
+            }))
+        ));
+
+        let cli = Cli::parse_from(["wordle", "play", "--hard"]);
+        assert!(matches!(
+            cli.command,
+            Some(Command::Play(PlayArgs { hard: true, .. }))
+        ));
+
+        let cli = Cli::parse_from(["wordle", "bench-opener", "--sample", "100"]);
+        assert!(matches!(
+            cli.command,
+            Some(Command::BenchOpener(BenchOpenerArgs {
+                sample: Some(100),
+                top: 10,
+                ..
+            }))
+        ));
+
+        let cli = Cli::parse_from(["wordle", "solve", "--word-size", "6"]);
+        assert!(matches!(
+            cli.command,
+            Some(Command::Solve(SolveArgs { word_size: 6, .. }))
+        ));
+
+        let cli = Cli::parse_from(["wordle", "--language", "es"]);
+        assert_eq!(Language::Spanish, cli.play.language);
+        assert!(Cli::try_parse_from(["wordle", "--language", "fr"]).is_err());
+        assert!(Cli::try_parse_from(["wordle", "solve", "--language", "fr"]).is_err());
+
+        // Playing is the default
+        let cli = Cli::parse_from(["wordle", "--hard"]);
+        assert!(cli.command.is_none() && cli.play.hard);
+    }
+
+    #[test]
+    fn plays_several_boards() {
+        let input = "sound\nwrong\nboost\n";
+        let stats = play(&["--boards", "2", "--seed", "1"], input);
+        assert_eq!(0, stats.games_played);
+        assert!(Cli::try_parse_from(["wordle", "--boards", "0"]).is_err());
+        assert!(Cli::try_parse_from(["wordle", "--boards", "2", "--absurdle"]).is_err());
+        assert!(Cli::try_parse_from(["wordle", "--boards", "2", "--hints", "1"]).is_err());
+        assert!(Cli::try_parse_from(["wordle", "--boards", "2", "--mercy-after", "3"]).is_err());
+    }
+
+    #[test]
+    fn boards_share_options_and_dictionary() {
+        let args = ["--boards", "3", "--no-repeats", "--dont-use-dictionary"];
+        let cli = Cli::parse_from([&["wordle"], &args[..]].concat()).play;
+        let words = vec!["sound".into(), "wrong".into(), "boost".into()];
+        let dict = Arc::new(VecDictionary::new(words, 5).unwrap());
+        let mut game = new_boards(Box::new(Arc::clone(&dict)), &cli.config(), cli.boards).unwrap();
+        // One handle here, plus the one all the boards share
+        assert_eq!(2, Arc::strong_count(&dict));
+
+        // None of the words is "dunso", so it's only accepted because any word is allowed
+        assert_eq!(3, game.guess_word("dunso").unwrap().len());
+        assert!(matches!(
+            game.guess_word("dunso"),
+            Err(GuessError::RepeatedGuess(_))
+        ));
+    }
+
+    #[test]
+    fn plays_absurdle_with_injected_dictionary() {
+        let stats = play(&["--absurdle"], "sound\nwrong\nboost\n");
+        assert_eq!(1, stats.games_won);
     }
 }
@@ -0,0 +1,308 @@
+use super::case::normalize_word;
+use super::clock::{Stopwatch, SystemClock};
+use super::dictionary::Dictionary;
+use super::errors::Result;
+use super::game::{
+    invalid_chars, CharAndStatus, CharStatus, GameOutcome, GameStatus, GuessError, GuessResult,
+    HintError, RoundResult, WordleGame, WordleGameImpl, MAX_SUGGESTION_DISTANCE,
+};
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// A game with no fixed target word. After every guess the game answers with the feedback that
+/// keeps the most candidate words alive, so the player only wins once a single word is left.
+pub struct AbsurdleGameImpl {
+    dictionary: Box<dyn Dictionary>,
+    candidates: Vec<String>,
+    status: GameStatus,
+    chars_status: HashMap<char, CharStatus>,
+    stopwatch: Stopwatch,
+}
+
+impl AbsurdleGameImpl {
+    pub fn new(dictionary: Box<dyn Dictionary>, max_guesses: usize) -> AbsurdleGameImpl {
+        let candidates = dictionary.words().map(|w| w.into()).collect();
+        let chars_status = dictionary
+            .available_chars()
+            .iter()
+            .map(|&c| (c, CharStatus::NotUsed))
+            .collect();
+
+        AbsurdleGameImpl {
+            dictionary,
+            candidates,
+            status: GameStatus::new_game(Some(max_guesses)),
+            chars_status,
+            stopwatch: Stopwatch::new(Box::new(SystemClock)),
+        }
+    }
+
+    // Words that are still consistent with every answer given so far, sorted
+    pub fn candidates(&self) -> &[String] {
+        &self.candidates
+    }
+
+    /// Checks that the game only bent the rules as far as Absurdle allows: every feedback given
+    /// so far must be what some dictionary word would have gotten. The feedback is replayed
+    /// against the whole dictionary, without trusting the candidates the game kept, and the first
+    /// word consistent with all of it is returned as proof. It's an error if there's no such
+    /// word, or if the candidates the game kept aren't exactly the consistent words.
+    pub fn audit(&self) -> Result<String> {
+        let consistent: Vec<String> = self
+            .dictionary
+            .words()
+            .filter(|word| {
+                self.status.guesses.iter().all(|guess| {
+                    WordleGameImpl::guess_result(word, guess.word()).is_ok_and(|r| r == *guess)
+                })
+            })
+            .map(|word| word.into())
+            .collect();
+
+        let witness = consistent
+            .first()
+            .ok_or("No word is consistent with all the feedback given")?;
+        if consistent != self.candidates {
+            return Err("The candidates don't match the feedback given".into());
+        }
+        Ok(witness.clone())
+    }
+
+    // Splits the candidates by the feedback they would give to `guess` and keeps the largest
+    // group. Ties go to the feedback revealing the least (fewest greens, then fewest yellows),
+    // and then to the group holding the alphabetically first word.
+    fn pick_result(&self, guess: &str) -> std::result::Result<GuessResult, GuessError> {
+        let mut buckets: HashMap<Vec<CharStatus>, Vec<&String>> = HashMap::new();
+        for candidate in self.candidates.iter() {
+            let result = WordleGameImpl::guess_result(candidate, guess)?;
+            let pattern = result.chars_result.iter().map(|cs| cs.1).collect();
+            buckets.entry(pattern).or_default().push(candidate);
+        }
+
+        let count = |pattern: &[CharStatus], status: CharStatus| {
+            pattern.iter().filter(|&&s| s == status).count()
+        };
+        let (_, bucket) = buckets
+            .iter()
+            .max_by(|(pattern_a, a), (pattern_b, b)| {
+                a.len()
+                    .cmp(&b.len())
+                    .then_with(|| {
+                        count(pattern_b, CharStatus::RightPosition)
+                            .cmp(&count(pattern_a, CharStatus::RightPosition))
+                    })
+                    .then_with(|| {
+                        count(pattern_b, CharStatus::WrongPosition)
+                            .cmp(&count(pattern_a, CharStatus::WrongPosition))
+                    })
+                    .then_with(|| b[0].cmp(a[0]))
+            })
+            .ok_or(GuessError::GameAlreadyOver)?;
+
+        WordleGameImpl::guess_result(bucket[0], guess)
+    }
+}
+
+impl WordleGame for AbsurdleGameImpl {
+    fn max_guesses(&self) -> Option<usize> {
+        self.status.max_guesses
+    }
+
+    fn guesses_used(&self) -> usize {
+        self.status.guesses.len()
+    }
+
+    fn guesses_remaining(&self) -> Option<usize> {
+        self.status.guesses_remaining()
+    }
+
+    fn status(&self) -> &GameStatus {
+        &self.status
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.stopwatch.elapsed()
+    }
+
+    // Once won, the word is the last guess; once lost, it's any word that was still possible
+    fn outcome(&self) -> Option<GameOutcome> {
+        let last = self.status.guesses.last()?;
+        if last.is_won() {
+            Some(GameOutcome::new(&self.status, last.word()))
+        } else if self.status.out_of_guesses() {
+            Some(GameOutcome::new(&self.status, &self.candidates[0]))
+        } else {
+            None
+        }
+    }
+
+    // There's no word to reveal letters from
+    fn use_hint(&mut self) -> std::result::Result<(usize, char), HintError> {
+        Err(HintError::Unavailable)
+    }
+
+    fn chars_status(&self) -> Vec<CharAndStatus> {
+        self.dictionary
+            .available_chars()
+            .iter()
+            .map(|&c| CharAndStatus(c, *self.chars_status.get(&c).unwrap()))
+            .collect()
+    }
+
+    fn guess_word<'a>(&'a mut self, word: &str) -> RoundResult<'a> {
+        let word = normalize_word(word, self.dictionary.case_sensitive());
+
+        let won = self.status.guesses.last().is_some_and(|g| g.is_won());
+        if won || self.status.out_of_guesses() {
+            return RoundResult::Error(GuessError::GameAlreadyOver);
+        }
+        if !self.dictionary.contains_word(&word) {
+            return match self.candidates.first() {
+                Some(candidate) if candidate.chars().count() != word.chars().count() => {
+                    RoundResult::Error(GuessError::WrongLength {
+                        expected: candidate.chars().count(),
+                        word: word.clone(),
+                        got: word.chars().count(),
+                    })
+                }
+                _ => {
+                    let invalid = invalid_chars(&word, &self.dictionary.available_chars());
+                    if !invalid.is_empty() {
+                        return RoundResult::Error(GuessError::InvalidCharacters(invalid));
+                    }
+                    let suggestion = self.dictionary.closest(&word, MAX_SUGGESTION_DISTANCE);
+                    RoundResult::Error(GuessError::NotInDictionary { word, suggestion })
+                }
+            };
+        }
+
+        let result = match self.pick_result(&word) {
+            Ok(result) => result,
+            Err(e) => return RoundResult::Error(e),
+        };
+
+        self.candidates.retain(|candidate| {
+            WordleGameImpl::guess_result(candidate, &word).is_ok_and(|r| r == result)
+        });
+        WordleGameImpl::merge_chars_status(&mut self.chars_status, &result);
+
+        let won = result.is_won();
+        self.status.guesses.push(result);
+        if won || self.status.out_of_guesses() {
+            self.stopwatch.stop();
+        }
+
+        if won {
+            RoundResult::Won(&self.status, word)
+        } else if self.status.out_of_guesses() {
+            RoundResult::Lost(&self.status, self.candidates[0].clone())
+        } else {
+            RoundResult::Continue(&self.status)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::dictionary::VecDictionary;
+    use super::*;
+
+    fn set_up_game(max_guesses: usize) -> AbsurdleGameImpl {
+        let words = [
+            "bound", "found", "hound", "mound", "pound", "sound", "wrong",
+        ];
+        let dict = VecDictionary::new(words.iter().map(|&w| w.into()), 5).unwrap();
+        AbsurdleGameImpl::new(Box::new(dict), max_guesses)
+    }
+
+    #[test]
+    fn keeps_largest_group_of_candidates() {
+        let mut game = set_up_game(6);
+        assert!(matches!(game.guess_word("wrong"), RoundResult::Continue(_)));
+        // WRONG only tells itself apart, so every -OUND word stays alive
+        assert_eq!(6, game.candidates().len());
+        assert!(!game.candidates().contains(&"WRONG".to_string()));
+    }
+
+    #[test]
+    fn cannot_win_while_other_candidates_remain() {
+        let mut game = set_up_game(6);
+        assert!(matches!(game.guess_word("sound"), RoundResult::Continue(_)));
+        assert!(!game.candidates().contains(&"SOUND".to_string()));
+    }
+
+    #[test]
+    fn wins_when_a_single_candidate_is_left() {
+        let mut game = set_up_game(10);
+        for word in ["bound", "found", "hound", "mound", "pound"] {
+            assert!(matches!(game.guess_word(word), RoundResult::Continue(_)));
+        }
+        assert_eq!(vec!["SOUND".to_string()], game.candidates());
+        assert!(matches!(game.guess_word("sound"), RoundResult::Won(_, ref w) if w == "SOUND"));
+        assert_eq!(6, game.outcome().unwrap().num_guesses);
+    }
+
+    #[test]
+    fn loses_after_max_guesses() {
+        let mut game = set_up_game(2);
+        assert!(matches!(game.guess_word("bound"), RoundResult::Continue(_)));
+        assert!(matches!(game.guess_word("found"), RoundResult::Lost(_, _)));
+        let outcome = game.outcome().unwrap();
+        assert!(!outcome.won);
+        assert!(game.candidates().contains(&outcome.word));
+        assert!(matches!(
+            game.guess_word("sound"),
+            RoundResult::Error(GuessError::GameAlreadyOver)
+        ));
+    }
+
+    #[test]
+    fn audit_finds_a_consistent_word() {
+        let mut game = set_up_game(6);
+        assert_eq!("BOUND", game.audit().unwrap());
+        for word in ["wrong", "bound", "found"] {
+            game.guess_word(word);
+            let witness = game.audit().unwrap();
+            assert!(game.candidates().contains(&witness));
+        }
+    }
+
+    #[test]
+    fn audit_catches_inconsistent_feedback() {
+        let mut game = set_up_game(6);
+        game.guess_word("bound");
+        // No word gets all greens for BOUND and then for FOUND
+        game.status
+            .guesses
+            .push(GuessResult::from_pattern("found", "GGGGG").unwrap());
+        assert!(game.audit().is_err());
+
+        let mut game = set_up_game(6);
+        game.guess_word("bound");
+        game.candidates.push("WRONG".into());
+        assert!(game.audit().is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_guesses() {
+        let mut game = set_up_game(6);
+        assert!(matches!(
+            game.guess_word("toolong"),
+            RoundResult::Error(GuessError::WrongLength {
+                expected: 5,
+                got: 7,
+                ..
+            })
+        ));
+        assert!(matches!(
+            game.guess_word("ddddd"),
+            RoundResult::Error(GuessError::NotInDictionary { .. })
+        ));
+        assert!(matches!(
+            game.guess_word("12345"),
+            RoundResult::Error(GuessError::InvalidCharacters(_))
+        ));
+        assert_eq!(0, game.guesses_used());
+    }
+}
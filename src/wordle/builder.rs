@@ -0,0 +1,192 @@
+use super::dictionary::Dictionary;
+use super::errors::Result;
+use super::game::{DuplicatePolicy, WordleGameImpl};
+
+pub struct WordleGameBuilder {
+    dictionary: Option<Box<dyn Dictionary>>,
+    word: Option<String>,
+    max_guesses: usize,
+    unlimited_guesses: bool,
+    hard_mode: bool,
+    hints: usize,
+    duplicate_policy: DuplicatePolicy,
+    reject_repeat_guesses: bool,
+    mercy_after: Option<usize>,
+    allow_any_word: bool,
+}
+
+impl Default for WordleGameBuilder {
+    fn default() -> WordleGameBuilder {
+        WordleGameBuilder {
+            dictionary: None,
+            word: None,
+            max_guesses: 6,
+            unlimited_guesses: false,
+            hard_mode: false,
+            hints: 0,
+            duplicate_policy: DuplicatePolicy::default(),
+            reject_repeat_guesses: false,
+            mercy_after: None,
+            allow_any_word: false,
+        }
+    }
+}
+
+impl WordleGameBuilder {
+    pub fn new() -> WordleGameBuilder {
+        WordleGameBuilder::default()
+    }
+
+    pub fn dictionary(mut self, dictionary: Box<dyn Dictionary>) -> WordleGameBuilder {
+        self.dictionary = Some(dictionary);
+        self
+    }
+
+    pub fn word(mut self, word: &str) -> WordleGameBuilder {
+        self.word = Some(word.into());
+        self
+    }
+
+    pub fn max_guesses(mut self, max_guesses: usize) -> WordleGameBuilder {
+        self.max_guesses = max_guesses;
+        self
+    }
+
+    // Overrides max_guesses, so the game can only end by finding the word
+    pub fn unlimited_guesses(mut self, unlimited_guesses: bool) -> WordleGameBuilder {
+        self.unlimited_guesses = unlimited_guesses;
+        self
+    }
+
+    pub fn hard_mode(mut self, hard_mode: bool) -> WordleGameBuilder {
+        self.hard_mode = hard_mode;
+        self
+    }
+
+    pub fn hints(mut self, hints: usize) -> WordleGameBuilder {
+        self.hints = hints;
+        self
+    }
+
+    pub fn duplicate_policy(mut self, duplicate_policy: DuplicatePolicy) -> WordleGameBuilder {
+        self.duplicate_policy = duplicate_policy;
+        self
+    }
+
+    pub fn reject_repeat_guesses(mut self, reject_repeat_guesses: bool) -> WordleGameBuilder {
+        self.reject_repeat_guesses = reject_repeat_guesses;
+        self
+    }
+
+    // None never reveals anything for free
+    pub fn mercy_after(mut self, failed_guesses: Option<usize>) -> WordleGameBuilder {
+        self.mercy_after = failed_guesses;
+        self
+    }
+
+    // Accepts guesses the dictionary doesn't know, as long as they use its letters
+    pub fn allow_any_word(mut self, allow_any_word: bool) -> WordleGameBuilder {
+        self.allow_any_word = allow_any_word;
+        self
+    }
+
+    pub fn build(self) -> Result<WordleGameImpl> {
+        let dictionary = self.dictionary.ok_or("A dictionary is required")?;
+        let word = self.word.ok_or("A word is required")?;
+
+        let mut game = WordleGameImpl::new(dictionary, &word, self.max_guesses, self.hard_mode)?
+            .with_hints(self.hints)
+            .with_duplicate_policy(self.duplicate_policy);
+        if self.reject_repeat_guesses {
+            game = game.with_repeat_guesses_rejected();
+        }
+        if self.allow_any_word {
+            game = game.with_any_word_allowed();
+        }
+        if let Some(failed_guesses) = self.mercy_after {
+            game = game.with_mercy_after(failed_guesses);
+        }
+        if self.unlimited_guesses {
+            Ok(game.with_unlimited_guesses())
+        } else {
+            Ok(game)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::dictionary::VecDictionary;
+    use super::super::game::{GuessError, RoundResult, WordleGame};
+    use super::*;
+
+    fn dictionary() -> Box<dyn Dictionary> {
+        let words = vec!["sound".into(), "wrong".into(), "boost".into()];
+        Box::new(VecDictionary::new(words, 5).unwrap())
+    }
+
+    #[test]
+    fn builds_game() {
+        let mut game = WordleGameBuilder::new()
+            .dictionary(dictionary())
+            .word("sound")
+            .max_guesses(2)
+            .hard_mode(true)
+            .hints(1)
+            .build()
+            .unwrap();
+        assert_eq!(Some(2), game.max_guesses());
+        assert_eq!(1, game.status().hints_remaining());
+        assert!(matches!(game.guess_word("wrong"), RoundResult::Continue(_)));
+        assert!(matches!(
+            game.guess_word("boost"),
+            RoundResult::Error(GuessError::HardModeViolation(_))
+        ));
+    }
+
+    #[test]
+    fn defaults_to_six_guesses() {
+        let game = WordleGameBuilder::new()
+            .dictionary(dictionary())
+            .word("sound")
+            .build()
+            .unwrap();
+        assert_eq!(Some(6), game.max_guesses());
+        assert_eq!(0, game.status().hints_remaining());
+    }
+
+    #[test]
+    fn builds_game_with_unlimited_guesses() {
+        let mut game = WordleGameBuilder::new()
+            .dictionary(dictionary())
+            .word("sound")
+            .max_guesses(1)
+            .unlimited_guesses(true)
+            .build()
+            .unwrap();
+        assert_eq!(None, game.max_guesses());
+        assert!(matches!(game.guess_word("wrong"), RoundResult::Continue(_)));
+        assert!(matches!(game.guess_word("sound"), RoundResult::Won(_, _)));
+    }
+
+    #[test]
+    fn requires_dictionary_and_word() {
+        assert!(WordleGameBuilder::new().word("sound").build().is_err());
+        assert!(WordleGameBuilder::new()
+            .dictionary(dictionary())
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_words() {
+        let build = |word: &str| {
+            WordleGameBuilder::new()
+                .dictionary(dictionary())
+                .word(word)
+                .build()
+        };
+        assert!(build("sounds").is_err());
+        assert!(build("mound").is_err());
+    }
+}
@@ -0,0 +1,91 @@
+use unicode_normalization::UnicodeNormalization;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Default)]
+pub enum LetterCase {
+    #[default]
+    Upper,
+    Lower,
+}
+
+impl LetterCase {
+    pub fn apply(&self, c: char) -> String {
+        match self {
+            LetterCase::Upper => c.to_uppercase().to_string(),
+            LetterCase::Lower => c.to_lowercase().to_string(),
+        }
+    }
+}
+
+/// Uppercases a word for comparison, using the full Unicode case mappings rather than assuming
+/// ASCII. Characters whose uppercase form takes more than one character, like ß → SS, are kept
+/// as they are so words never change length.
+///
+/// The word is first normalized to NFC, so that a letter like Ñ is always a single character
+/// whether it was typed precomposed or as N followed by a combining tilde.
+///
+/// Folding doesn't depend on the language: the default mappings suit English, Spanish and French,
+/// but fold both the Turkish dotted i and dotless ı to I, making them the same letter. Word lists
+/// for alphabets like Turkish's should be case sensitive (see `VecDictionary::new_case_sensitive`),
+/// which keeps every letter as it was written.
+pub fn fold_case(word: &str) -> String {
+    word.nfc()
+        .map(|c| {
+            let mut upper = c.to_uppercase();
+            match (upper.next(), upper.next()) {
+                (Some(u), None) => u,
+                _ => c,
+            }
+        })
+        .collect()
+}
+
+// Case-sensitive words are only normalized to NFC, so e.g. "NaCl" stays as it was written
+pub fn normalize_word(word: &str, case_sensitive: bool) -> String {
+    if case_sensitive {
+        word.nfc().collect()
+    } else {
+        fold_case(word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn case_sensitive_words_keep_their_case() {
+        assert_eq!("NaCl", normalize_word("NaCl", true));
+        assert_eq!("NACL", normalize_word("NaCl", false));
+        assert_eq!("Añ", normalize_word("An\u{303}", true));
+    }
+
+    #[test]
+    fn folds_turkish_dotless_i() {
+        assert_eq!("ILIK", fold_case("ılık"));
+        // Folding can't tell the Turkish letters apart, only case-sensitive words keep them apart
+        assert_eq!(fold_case("ılık"), fold_case("ilik"));
+        assert_ne!(normalize_word("ılık", true), normalize_word("ilik", true));
+        // The dotted capital İ is already uppercase and must stay a single character
+        assert_eq!("İYI", fold_case("İyi"));
+        assert_eq!(3, fold_case("İyi").chars().count());
+    }
+
+    #[test]
+    fn folding_never_changes_length() {
+        assert_eq!("STRAßE", fold_case("straße"));
+        assert_eq!("ÑANDÚ", fold_case("ñandú"));
+    }
+
+    #[test]
+    fn composes_decomposed_letters() {
+        assert_eq!("AÑO", fold_case("an\u{303}o"));
+        assert_eq!(3, fold_case("an\u{303}o").chars().count());
+        assert_eq!(fold_case("canción"), fold_case("cancio\u{301}n"));
+    }
+
+    #[test]
+    fn applies_letter_case() {
+        assert_eq!("a", LetterCase::Lower.apply('A'));
+        assert_eq!("Ñ", LetterCase::Upper.apply('ñ'));
+    }
+}
@@ -0,0 +1,97 @@
+use std::time::{Duration, Instant};
+
+pub trait Clock {
+    fn now(&self) -> Instant;
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+// Measures how long a game took, from the moment it starts until it's won or lost
+pub(crate) struct Stopwatch {
+    clock: Box<dyn Clock>,
+    started: Instant,
+    stopped: Option<Instant>,
+}
+
+impl Stopwatch {
+    pub(crate) fn new(clock: Box<dyn Clock>) -> Stopwatch {
+        let started = clock.now();
+        Stopwatch {
+            clock,
+            started,
+            stopped: None,
+        }
+    }
+
+    pub(crate) fn restart(&mut self) {
+        self.started = self.clock.now();
+        self.stopped = None;
+    }
+
+    pub(crate) fn stop(&mut self) {
+        self.stopped.get_or_insert(self.clock.now());
+    }
+
+    // Keeps timing after having been stopped, counting the time in between too
+    pub(crate) fn resume(&mut self) {
+        self.stopped = None;
+    }
+
+    pub(crate) fn elapsed(&self) -> Duration {
+        let end = self.stopped.unwrap_or_else(|| self.clock.now());
+        end.duration_since(self.started)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    // A clock that only moves when told to
+    #[derive(Clone)]
+    pub(crate) struct FakeClock {
+        now: Rc<Cell<Instant>>,
+    }
+
+    impl FakeClock {
+        pub(crate) fn new() -> FakeClock {
+            FakeClock {
+                now: Rc::new(Cell::new(Instant::now())),
+            }
+        }
+
+        pub(crate) fn advance(&self, duration: Duration) {
+            self.now.set(self.now.get() + duration);
+        }
+    }
+
+    impl Clock for FakeClock {
+        fn now(&self) -> Instant {
+            self.now.get()
+        }
+    }
+
+    #[test]
+    fn stopwatch_stops_once() {
+        let clock = FakeClock::new();
+        let mut stopwatch = Stopwatch::new(Box::new(clock.clone()));
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(Duration::from_secs(5), stopwatch.elapsed());
+
+        stopwatch.stop();
+        clock.advance(Duration::from_secs(5));
+        stopwatch.stop();
+        assert_eq!(Duration::from_secs(5), stopwatch.elapsed());
+
+        stopwatch.restart();
+        assert_eq!(Duration::ZERO, stopwatch.elapsed());
+    }
+}
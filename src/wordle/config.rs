@@ -0,0 +1,189 @@
+use super::builder::WordleGameBuilder;
+use super::dictionary::{available_word_sizes, Dictionary, LanguageDictionary};
+use super::errors::Result;
+use super::language::Language;
+
+use std::path::PathBuf;
+
+/// The options a game is played with, gathered in one place. Frontends like the CLI parse their
+/// arguments into it once and hand it around instead of each option separately.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameConfig {
+    pub word_size: usize,
+    // None leaves it to the kind of game, e.g. 6 for a single board
+    pub num_guesses: Option<usize>,
+    pub unlimited_guesses: bool,
+    pub hard_mode: bool,
+    pub reject_repeat_guesses: bool,
+    // Skips checking that guesses are in the dictionary
+    pub allow_any_word: bool,
+    pub hints: usize,
+    // Failed guesses after which a letter is revealed for free
+    pub mercy_after: Option<usize>,
+    pub language: Language,
+    // Picks the word deterministically, so the same puzzle can be played again
+    pub seed: Option<u64>,
+    // Word list to use instead of the built-in dictionary for the language
+    pub dictionary: Option<PathBuf>,
+}
+
+impl Default for GameConfig {
+    fn default() -> GameConfig {
+        GameConfig {
+            word_size: 5,
+            num_guesses: None,
+            unlimited_guesses: false,
+            hard_mode: false,
+            reject_repeat_guesses: false,
+            allow_any_word: false,
+            hints: 0,
+            mercy_after: None,
+            language: Language::English,
+            seed: None,
+            dictionary: None,
+        }
+    }
+}
+
+impl GameConfig {
+    pub fn new() -> GameConfig {
+        GameConfig::default()
+    }
+
+    pub fn with_word_size(mut self, word_size: usize) -> GameConfig {
+        self.word_size = word_size;
+        self
+    }
+
+    pub fn with_num_guesses(mut self, num_guesses: usize) -> GameConfig {
+        self.num_guesses = Some(num_guesses);
+        self
+    }
+
+    pub fn with_unlimited_guesses(mut self, unlimited_guesses: bool) -> GameConfig {
+        self.unlimited_guesses = unlimited_guesses;
+        self
+    }
+
+    pub fn with_hard_mode(mut self, hard_mode: bool) -> GameConfig {
+        self.hard_mode = hard_mode;
+        self
+    }
+
+    pub fn with_reject_repeat_guesses(mut self, reject_repeat_guesses: bool) -> GameConfig {
+        self.reject_repeat_guesses = reject_repeat_guesses;
+        self
+    }
+
+    pub fn with_any_word_allowed(mut self, allow_any_word: bool) -> GameConfig {
+        self.allow_any_word = allow_any_word;
+        self
+    }
+
+    pub fn with_hints(mut self, hints: usize) -> GameConfig {
+        self.hints = hints;
+        self
+    }
+
+    pub fn with_mercy_after(mut self, failed_guesses: usize) -> GameConfig {
+        self.mercy_after = Some(failed_guesses);
+        self
+    }
+
+    pub fn with_language(mut self, language: Language) -> GameConfig {
+        self.language = language;
+        self
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> GameConfig {
+        self.seed = Some(seed);
+        self
+    }
+
+    pub fn with_dictionary(mut self, path: PathBuf) -> GameConfig {
+        self.dictionary = Some(path);
+        self
+    }
+
+    // The guesses of a single board game
+    pub fn max_guesses(&self) -> usize {
+        self.num_guesses.unwrap_or(6)
+    }
+
+    pub fn load_dictionary(&self) -> Result<LanguageDictionary> {
+        match &self.dictionary {
+            Some(path) => LanguageDictionary::from_file(self.language, path, self.word_size),
+            None => {
+                let sizes = available_word_sizes(self.language)?;
+                if !sizes.contains(&self.word_size) {
+                    let sizes: Vec<String> = sizes.iter().map(|s| s.to_string()).collect();
+                    return Err(format!(
+                        "There are no {} letter words (available sizes: {})",
+                        self.word_size,
+                        sizes.join(", ")
+                    )
+                    .into());
+                }
+                LanguageDictionary::new(self.language, self.word_size)
+            }
+        }
+    }
+
+    // The word to guess is left to the caller, since it can come from so many places
+    pub fn game_builder(&self, dictionary: Box<dyn Dictionary>) -> WordleGameBuilder {
+        WordleGameBuilder::new()
+            .dictionary(dictionary)
+            .max_guesses(self.max_guesses())
+            .hard_mode(self.hard_mode)
+            .reject_repeat_guesses(self.reject_repeat_guesses)
+            .allow_any_word(self.allow_any_word)
+            .hints(self.hints)
+            .mercy_after(self.mercy_after)
+            .unlimited_guesses(self.unlimited_guesses)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::dictionary::VecDictionary;
+    use super::super::game::{GuessError, RoundResult, WordleGame};
+    use super::*;
+
+    #[test]
+    fn defaults_to_a_standard_game() {
+        let config = GameConfig::new();
+        assert_eq!(5, config.word_size);
+        assert_eq!(6, config.max_guesses());
+        assert_eq!(Language::English, config.language);
+        assert!(!config.hard_mode && !config.unlimited_guesses);
+        assert_eq!(Some(3), config.with_num_guesses(3).num_guesses);
+    }
+
+    #[test]
+    fn builds_game_from_config() {
+        let config = GameConfig::new()
+            .with_num_guesses(2)
+            .with_hard_mode(true)
+            .with_hints(1);
+        let words = vec!["sound".into(), "wrong".into(), "boost".into()];
+        let dict = VecDictionary::new(words, 5).unwrap();
+        let mut game = config
+            .game_builder(Box::new(dict))
+            .word("sound")
+            .build()
+            .unwrap();
+        assert_eq!(Some(2), game.max_guesses());
+        assert_eq!(1, game.status().hints_remaining());
+        assert!(matches!(game.guess_word("wrong"), RoundResult::Continue(_)));
+        assert!(matches!(
+            game.guess_word("boost"),
+            RoundResult::Error(GuessError::HardModeViolation(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_missing_word_sizes() {
+        let config = GameConfig::new().with_word_size(40);
+        assert!(config.load_dictionary().is_err());
+    }
+}
@@ -0,0 +1,156 @@
+use super::game::{CharStatus, GuessResult};
+
+use std::collections::{BTreeMap, BTreeSet};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct PresentLetter {
+    // The word has at least this many copies of the letter
+    pub min_count: usize,
+    // Set when a guess had more copies than the word, so the exact count is known
+    pub max_count: Option<usize>,
+    // Positions where the letter is known not to be
+    pub excluded_positions: BTreeSet<usize>,
+}
+
+/// Everything the guesses so far reveal about the word. A letter guessed twice may be both present
+/// (one copy marked as misplaced) and rejected (the other copy marked as not in the word): it then
+/// shows up in `present` with an exact count, and never in `absent`.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Constraints {
+    // The letter at each position, if known
+    pub known: Vec<Option<char>>,
+    pub present: BTreeMap<char, PresentLetter>,
+    pub absent: BTreeSet<char>,
+}
+
+impl Constraints {
+    pub fn from_guesses(guesses: &[GuessResult]) -> Constraints {
+        let mut constraints = Constraints::default();
+
+        for guess in guesses.iter() {
+            if constraints.known.len() < guess.chars_result.len() {
+                constraints.known.resize(guess.chars_result.len(), None);
+            }
+
+            let mut found: BTreeMap<char, usize> = BTreeMap::new();
+            let mut rejected: BTreeSet<char> = BTreeSet::new();
+            for (pos, cs) in guess.chars_result.iter().enumerate() {
+                match cs.1 {
+                    CharStatus::RightPosition => {
+                        constraints.known[pos] = Some(cs.0);
+                        *found.entry(cs.0).or_default() += 1;
+                    }
+                    CharStatus::WrongPosition => {
+                        *found.entry(cs.0).or_default() += 1;
+                        constraints.exclude(cs.0, pos);
+                    }
+                    CharStatus::NotInWord => {
+                        rejected.insert(cs.0);
+                        constraints.exclude(cs.0, pos);
+                    }
+                    CharStatus::NotUsed => {}
+                }
+            }
+
+            for &c in found.keys().chain(rejected.iter()) {
+                let count = found.get(&c).copied().unwrap_or(0);
+                let present = constraints.present.entry(c).or_default();
+                present.min_count = present.min_count.max(count);
+                if rejected.contains(&c) {
+                    present.max_count = Some(count);
+                }
+            }
+        }
+
+        // Only letters with every copy rejected are really absent; the others were just excluded
+        // from some positions
+        let absent: Vec<char> = constraints
+            .present
+            .iter()
+            .filter(|(_, present)| present.max_count == Some(0))
+            .map(|(&c, _)| c)
+            .collect();
+        for c in absent {
+            constraints.present.remove(&c);
+            constraints.absent.insert(c);
+        }
+
+        constraints
+    }
+
+    fn exclude(&mut self, c: char, pos: usize) {
+        let present = self.present.entry(c).or_default();
+        present.excluded_positions.insert(pos);
+    }
+
+    // Whether the word could still be the answer
+    pub fn allows(&self, word: &str) -> bool {
+        let chars: Vec<char> = word.chars().collect();
+        if chars.len() < self.known.len() {
+            return false;
+        }
+
+        let known_ok = self
+            .known
+            .iter()
+            .zip(chars.iter())
+            .all(|(known, c)| known.is_none_or(|k| k == *c));
+        let absent_ok = !chars.iter().any(|c| self.absent.contains(c));
+        let present_ok = self.present.iter().all(|(&c, present)| {
+            let count = chars.iter().filter(|&&w| w == c).count();
+            count >= present.min_count
+                && present.max_count.is_none_or(|max| count <= max)
+                && present
+                    .excluded_positions
+                    .iter()
+                    .all(|&pos| chars.get(pos) != Some(&c))
+        });
+
+        known_ok && absent_ok && present_ok
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::game::WordleGameImpl;
+    use super::*;
+
+    fn constraints(target: &str, guesses: &[&str]) -> Constraints {
+        let guesses: Vec<GuessResult> = guesses
+            .iter()
+            .map(|guess| WordleGameImpl::guess_result(target, guess).unwrap())
+            .collect();
+        Constraints::from_guesses(&guesses)
+    }
+
+    #[test]
+    fn summarizes_feedback() {
+        let constraints = constraints("SOUND", &["WRONG"]);
+        assert_eq!(vec![None, None, None, Some('N'), None], constraints.known);
+        assert_eq!(BTreeSet::from(['G', 'R', 'W']), constraints.absent);
+
+        let o = &constraints.present[&'O'];
+        assert_eq!(1, o.min_count);
+        assert_eq!(None, o.max_count);
+        assert_eq!(BTreeSet::from([2]), o.excluded_positions);
+    }
+
+    #[test]
+    fn repeated_letter_can_be_present_and_excluded() {
+        // One O is right, the other one is surplus
+        let constraints = constraints("SOUND", &["BOOST"]);
+        assert!(!constraints.absent.contains(&'O'));
+        let o = &constraints.present[&'O'];
+        assert_eq!(1, o.min_count);
+        assert_eq!(Some(1), o.max_count);
+        assert_eq!(BTreeSet::from([2]), o.excluded_positions);
+    }
+
+    #[test]
+    fn target_is_always_allowed() {
+        let constraints = constraints("SOUND", &["WRONG", "BOOST"]);
+        assert!(constraints.allows("SOUND"));
+        assert!(!constraints.allows("MOODS"));
+        assert!(!constraints.allows("WOUND"));
+    }
+}
@@ -0,0 +1,38 @@
+use super::dictionary::Dictionary;
+use super::errors::Result;
+
+use chrono::{Datelike, NaiveDate};
+
+/// Picks the word of the day. The date is turned into a seed by counting the days since
+/// January 1st of year 1 (so 0001-01-01 is seed 1), and that seed is fed to
+/// `Dictionary::get_random_word_seeded`. Everyone using the same dictionary gets the same word on
+/// the same date.
+pub fn daily_word(dict: &dyn Dictionary, size: usize, date: NaiveDate) -> Result<String> {
+    let seed = date.num_days_from_ce() as u64;
+    dict.get_random_word_seeded(size, seed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::dictionary::EnglishDictionary;
+    use super::*;
+
+    #[test]
+    fn same_date_gives_same_word() {
+        let dict = EnglishDictionary::new(5).unwrap();
+        let date = NaiveDate::from_ymd_opt(2022, 1, 15).unwrap();
+        let word = daily_word(&dict, 5, date).unwrap();
+        assert_eq!(word, daily_word(&dict, 5, date).unwrap());
+        assert_eq!(word, dict.get_random_word_seeded(5, 738170).unwrap());
+    }
+
+    #[test]
+    fn different_dates_give_different_words() {
+        let dict = EnglishDictionary::new(5).unwrap();
+        let words: Vec<String> = (1..=5)
+            .map(|day| NaiveDate::from_ymd_opt(2022, 1, day).unwrap())
+            .map(|date| daily_word(&dict, 5, date).unwrap())
+            .collect();
+        assert!(words.windows(2).any(|w| w[0] != w[1]));
+    }
+}
@@ -1,44 +1,202 @@
-use super::errors::Result;
+use super::case::{fold_case, normalize_word};
+use super::errors::{Result, ResultExt};
+use super::language::Language;
 
-use rand::Rng;
-use std::collections::HashSet;
-use std::fs::File;
+use log::{debug, trace};
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::cell::OnceCell;
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::fs::{self, File};
 use std::io::{self, BufRead};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 
 pub trait Dictionary {
     fn get_random_word(&self, size: usize) -> Result<String>;
+    fn get_random_word_seeded(&self, size: usize, seed: u64) -> Result<String>;
     fn contains_word(&self, word: &str) -> bool;
     fn available_chars(&self) -> Vec<char>;
+    // Every word in the dictionary, uppercased (unless case sensitive) and in alphabetical order
+    fn words(&self) -> Box<dyn Iterator<Item = &str> + '_>;
+    // The number of characters of every word, or None if words come in several sizes
+    fn word_size(&self) -> Option<usize>;
+
+    // Whether words keep their case, for word lists like chemical formulas ("NaCl"). Guesses must
+    // then match the case too, and available_chars lists lowercase and uppercase letters apart.
+    fn case_sensitive(&self) -> bool {
+        false
+    }
+
+    // Picks common words more often than rare ones. Dictionaries without frequency data pick
+    // uniformly.
+    fn get_random_word_weighted(&self, size: usize) -> Result<String> {
+        self.get_random_word(size)
+    }
+
+    // The word of the same length with the fewest edits from `word`, if any is at most
+    // `max_distance` edits away, so it could be guessed instead. Ties go to the first word in
+    // alphabetical order.
+    fn closest(&self, word: &str, max_distance: usize) -> Option<String> {
+        let word = normalize_word(word, self.case_sensitive());
+        let size = word.chars().count();
+        let mut closest: Option<(usize, &str)> = None;
+        for candidate in self.words() {
+            if candidate.chars().count() != size {
+                continue;
+            }
+            let distance = edit_distance(&word, candidate);
+            if distance <= max_distance && closest.is_none_or(|(best, _)| distance < best) {
+                closest = Some((distance, candidate));
+            }
+        }
+        closest.map(|(_, candidate)| candidate.into())
+    }
+
+    // The words with every letter in `must` and none in `banned`, in alphabetical order. Letters
+    // are case folded like words are.
+    fn words_with_letters(&self, must: &[char], banned: &[char]) -> Vec<String> {
+        let normalize = |letters: &[char]| -> Vec<char> {
+            let letters: String = letters.iter().collect();
+            normalize_word(&letters, self.case_sensitive())
+                .chars()
+                .collect()
+        };
+        let (must, banned) = (normalize(must), normalize(banned));
+        self.words()
+            .filter(|word| {
+                must.iter().all(|&c| word.contains(c)) && !word.chars().any(|c| banned.contains(&c))
+            })
+            .map(|word| word.into())
+            .collect()
+    }
 }
 
-pub struct EnglishDictionary {
+// Levenshtein distance, counted in characters
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+pub struct VecDictionary {
     words: HashSet<String>,
+    // Same words as the set, sorted, so random picks are a plain index lookup
+    word_list: Vec<String>,
     word_size: usize,
+    // Distinct characters across all words, sorted
+    chars: Vec<char>,
+    // Selection weights for each word in `word_list`, if frequency data was loaded
+    weights: Option<WeightedIndex<f64>>,
+    case_sensitive: bool,
 }
 
-impl EnglishDictionary {
-    pub fn new(word_size: usize) -> Result<EnglishDictionary> {
-        let mut words = HashSet::<String>::new();
+impl VecDictionary {
+    pub fn new(words: impl IntoIterator<Item = String>, word_size: usize) -> Result<VecDictionary> {
+        VecDictionary::build(words, word_size, false, None)
+    }
 
-        let file = File::open(format!("dictionaries/english/{}.txt", word_size))?;
-        let lines = io::BufReader::new(file).lines();
-        lines.into_iter().filter_map(|w| w.ok()).for_each(|w| {
-            words.insert(w.to_uppercase());
-        });
+    // Keeps the words as they're given instead of uppercasing them
+    pub fn new_case_sensitive(
+        words: impl IntoIterator<Item = String>,
+        word_size: usize,
+    ) -> Result<VecDictionary> {
+        VecDictionary::build(words, word_size, true, None)
+    }
 
-        if words.is_empty() {
-            Err("Error loading dictionary, dictionary is empty".into())
-        } else {
-            Ok(EnglishDictionary {
-                words: words,
-                word_size: word_size,
-            })
+    // Words over an alphabet of arbitrary symbols, like emoji or card suits. The symbols are used
+    // as they are, with no case folding, and make up the whole keyboard even if no word uses some.
+    pub fn with_symbols(
+        words: impl IntoIterator<Item = String>,
+        word_size: usize,
+        symbols: Vec<char>,
+    ) -> Result<VecDictionary> {
+        VecDictionary::build(words, word_size, true, Some(symbols))
+    }
+
+    fn build(
+        words: impl IntoIterator<Item = String>,
+        word_size: usize,
+        case_sensitive: bool,
+        symbols: Option<Vec<char>>,
+    ) -> Result<VecDictionary> {
+        let mut word_set = HashSet::<String>::new();
+        for word in words {
+            let word = normalize_word(&word, case_sensitive);
+            let num_chars = word.chars().count();
+            if num_chars != word_size {
+                return Err(format!(
+                    "Word \"{}\" has {} characters, expected {}",
+                    word, num_chars, word_size
+                )
+                .into());
+            }
+            word_set.insert(word);
+        }
+
+        if word_set.is_empty() {
+            return Err("Error loading dictionary, dictionary is empty".into());
         }
+
+        let mut word_list: Vec<String> = word_set.iter().cloned().collect();
+        word_list.sort();
+
+        let mut chars: Vec<char> = match symbols {
+            Some(symbols) => {
+                let alphabet: HashSet<char> = symbols.iter().copied().collect();
+                if let Some(word) = word_list
+                    .iter()
+                    .find(|w| w.chars().any(|c| !alphabet.contains(&c)))
+                {
+                    return Err(
+                        format!("Word \"{}\" uses symbols outside the alphabet", word).into(),
+                    );
+                }
+                alphabet.into_iter().collect()
+            }
+            None => word_set
+                .iter()
+                .flat_map(|w| w.chars())
+                .collect::<HashSet<char>>()
+                .into_iter()
+                .collect(),
+        };
+        chars.sort();
+
+        Ok(VecDictionary {
+            words: word_set,
+            word_list,
+            word_size,
+            chars,
+            weights: None,
+            case_sensitive,
+        })
     }
-}
 
-impl Dictionary for EnglishDictionary {
-    fn get_random_word(&self, size: usize) -> Result<String> {
+    // Words missing from `frequencies` are never picked by weighted selection
+    pub fn set_frequencies(&mut self, frequencies: &HashMap<String, f64>) -> Result<()> {
+        let weights = self
+            .word_list
+            .iter()
+            .map(|w| frequencies.get(w).copied().unwrap_or(0.0));
+        let weights =
+            WeightedIndex::new(weights).map_err(|e| format!("Invalid word frequencies: {}", e))?;
+        self.weights = Some(weights);
+        Ok(())
+    }
+
+    fn check_size(&self, size: usize) -> Result<()> {
         if self.word_size != size {
             return Err(format!(
                 "Tried to get a word of {} characters using a dictionary of {} characters",
@@ -46,23 +204,1145 @@ impl Dictionary for EnglishDictionary {
             )
             .into());
         }
+        Ok(())
+    }
+
+    fn pick_word<R: Rng>(&self, size: usize, rng: &mut R) -> Result<String> {
+        self.check_size(size)?;
+
+        let r = rng.gen_range(0..self.word_list.len());
+        trace!(
+            "Picked word {} ({} of {})",
+            self.word_list[r],
+            r,
+            self.word_list.len()
+        );
+
+        Ok(self.word_list[r].clone())
+    }
+}
+
+// One word per line, ignoring surrounding whitespace and blank lines
+fn parse_word_list<S: AsRef<str>>(lines: impl Iterator<Item = S>) -> impl Iterator<Item = String> {
+    lines
+        .map(|w| w.as_ref().trim().to_string())
+        .filter(|w| !w.is_empty())
+}
+
+// Frequency files have one word per line followed by its weight, separated by whitespace
+fn read_frequencies(path: &Path) -> Result<HashMap<String, f64>> {
+    let file = File::open(path)?;
+    let mut frequencies = HashMap::new();
+    for (n, line) in io::BufReader::new(file).lines().enumerate() {
+        let line = line?;
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        match fields[..] {
+            [] => {}
+            [word, weight] => {
+                let weight: f64 = weight.parse().map_err(|_| {
+                    format!(
+                        "{}:{}: invalid weight \"{}\"",
+                        path.display(),
+                        n + 1,
+                        weight
+                    )
+                })?;
+                frequencies.insert(fold_case(word), weight);
+            }
+            _ => {
+                return Err(
+                    format!("{}:{}: expected a word and a weight", path.display(), n + 1).into(),
+                )
+            }
+        }
+    }
+    Ok(frequencies)
+}
+
+impl Dictionary for VecDictionary {
+    fn get_random_word(&self, size: usize) -> Result<String> {
+        self.pick_word(size, &mut rand::thread_rng())
+    }
+
+    fn get_random_word_seeded(&self, size: usize, seed: u64) -> Result<String> {
+        self.pick_word(size, &mut StdRng::seed_from_u64(seed))
+    }
+
+    fn contains_word(&self, word: &str) -> bool {
+        self.words
+            .contains(&normalize_word(word, self.case_sensitive))
+    }
+
+    fn available_chars(&self) -> Vec<char> {
+        self.chars.clone()
+    }
+
+    fn words(&self) -> Box<dyn Iterator<Item = &str> + '_> {
+        Box::new(self.word_list.iter().map(|w| w.as_str()))
+    }
+
+    fn word_size(&self) -> Option<usize> {
+        Some(self.word_size)
+    }
+
+    fn case_sensitive(&self) -> bool {
+        self.case_sensitive
+    }
+
+    fn get_random_word_weighted(&self, size: usize) -> Result<String> {
+        match &self.weights {
+            Some(weights) => {
+                self.check_size(size)?;
+                let r = weights.sample(&mut rand::thread_rng());
+                Ok(self.word_list[r].clone())
+            }
+            None => self.get_random_word(size),
+        }
+    }
+}
+
+#[cfg(feature = "embedded")]
+include!(concat!(env!("OUT_DIR"), "/embedded_dictionaries.rs"));
+
+#[cfg(feature = "embedded")]
+fn embedded_word_list(language: Language, word_size: usize) -> Option<&'static str> {
+    EMBEDDED_DICTIONARIES
+        .iter()
+        .find(|(directory, size, _)| *directory == language.directory() && *size == word_size)
+        .map(|(_, _, words)| *words)
+}
+
+// Files ending in .gz are decompressed on the fly
+fn open_word_list(path: &Path) -> Result<Box<dyn BufRead>> {
+    debug!("Reading word list {}", path.display());
+    let file = File::open(path)?;
+    if path.extension().is_some_and(|e| e == "gz") {
+        #[cfg(feature = "gzip")]
+        return Ok(Box::new(io::BufReader::new(flate2::read::GzDecoder::new(
+            file,
+        ))));
+        #[cfg(not(feature = "gzip"))]
+        return Err("Reading compressed dictionaries requires the gzip feature".into());
+    }
+    Ok(Box::new(io::BufReader::new(file)))
+}
+
+// The word list for a size, compressed if there's such a file and it can be read
+fn word_list_path(language: Language, word_size: usize) -> PathBuf {
+    let path = PathBuf::from(format!(
+        "dictionaries/{}/{}.txt",
+        language.directory(),
+        word_size
+    ));
+    #[cfg(feature = "gzip")]
+    {
+        let compressed = path.with_extension("txt.gz");
+        if compressed.exists() {
+            return compressed;
+        }
+    }
+    path
+}
+
+pub struct LanguageDictionary {
+    words: VecDictionary,
+    // Words the target is picked from, if narrower than the words accepted as guesses
+    answers: Option<VecDictionary>,
+    language: Language,
+}
+
+impl LanguageDictionary {
+    pub fn new(language: Language, word_size: usize) -> Result<LanguageDictionary> {
+        let path = word_list_path(language, word_size);
+
+        #[cfg(feature = "embedded")]
+        if let Some(word_list) = embedded_word_list(language, word_size) {
+            debug!("Using the embedded word list for {}", path.display());
+            let words = VecDictionary::new(parse_word_list(word_list.lines()), word_size)
+                .chain_err(|| format!("Error loading embedded dictionary {}", path.display()))?;
+            return Ok(LanguageDictionary {
+                words,
+                answers: None,
+                language,
+            });
+        }
+
+        let reader = open_word_list(&path).map_err(|_| {
+            format!(
+                "No dictionary for word size {} (looked in {})",
+                word_size,
+                path.display()
+            )
+        })?;
+        LanguageDictionary::from_reader(language, reader, word_size)
+            .chain_err(|| format!("Error loading dictionary {}", path.display()))
+    }
+
+    pub fn from_file(
+        language: Language,
+        path: &Path,
+        word_size: usize,
+    ) -> Result<LanguageDictionary> {
+        let reader = open_word_list(path)
+            .chain_err(|| format!("Couldn't open dictionary {}", path.display()))?;
+        LanguageDictionary::from_reader(language, reader, word_size)
+            .chain_err(|| format!("Error loading dictionary {}", path.display()))
+    }
+
+    // One word per line, like the files under dictionaries/
+    pub fn from_reader(
+        language: Language,
+        reader: impl BufRead,
+        word_size: usize,
+    ) -> Result<LanguageDictionary> {
+        let lines = reader.lines().collect::<io::Result<Vec<String>>>()?;
+        let words = VecDictionary::new(parse_word_list(lines.iter()), word_size)?;
+        debug!(
+            "Loaded {} words of {} letters",
+            words.word_list.len(),
+            word_size
+        );
+
+        Ok(LanguageDictionary {
+            words,
+            answers: None,
+            language,
+        })
+    }
+
+    // Like real Wordle: targets only come from the (usually curated) answers, while guesses can be
+    // any word from either list
+    pub fn with_answers(
+        language: Language,
+        answers_path: &Path,
+        allowed_path: &Path,
+        word_size: usize,
+    ) -> Result<LanguageDictionary> {
+        let answers = LanguageDictionary::from_file(language, answers_path, word_size)?.words;
+        let allowed = LanguageDictionary::from_file(language, allowed_path, word_size)?.words;
+        let words = allowed.words().chain(answers.words()).map(String::from);
+        let words = VecDictionary::new(words, word_size)?;
+        debug!(
+            "Using {} of {} words as answers",
+            answers.word_list.len(),
+            words.word_list.len()
+        );
+
+        Ok(LanguageDictionary {
+            words,
+            answers: Some(answers),
+            language,
+        })
+    }
+
+    fn answers(&self) -> &VecDictionary {
+        self.answers.as_ref().unwrap_or(&self.words)
+    }
+
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    pub fn load_frequencies(&mut self, path: &Path) -> Result<()> {
+        let frequencies = read_frequencies(path)?;
+        debug!(
+            "Loaded frequencies for {} words from {}",
+            frequencies.len(),
+            path.display()
+        );
+        if let Some(answers) = &mut self.answers {
+            answers.set_frequencies(&frequencies)?;
+        }
+        self.words.set_frequencies(&frequencies)
+    }
+}
+
+impl Dictionary for LanguageDictionary {
+    fn get_random_word(&self, size: usize) -> Result<String> {
+        self.answers().get_random_word(size)
+    }
+
+    fn get_random_word_seeded(&self, size: usize, seed: u64) -> Result<String> {
+        self.answers().get_random_word_seeded(size, seed)
+    }
+
+    fn contains_word(&self, word: &str) -> bool {
+        self.words.contains_word(word)
+    }
+
+    fn available_chars(&self) -> Vec<char> {
+        // Keep the language's own letter order (e.g. Ñ right after N), with any character outside
+        // the alphabet at the end
+        let alphabet = self.language.alphabet();
+        let mut chars = self.words.available_chars();
+        chars.sort_by_key(|c| {
+            alphabet
+                .iter()
+                .position(|a| a == c)
+                .unwrap_or(alphabet.len())
+        });
+        chars
+    }
+
+    fn words(&self) -> Box<dyn Iterator<Item = &str> + '_> {
+        self.words.words()
+    }
+
+    fn word_size(&self) -> Option<usize> {
+        self.words.word_size()
+    }
+
+    fn get_random_word_weighted(&self, size: usize) -> Result<String> {
+        self.answers().get_random_word_weighted(size)
+    }
+}
+
+pub struct EnglishDictionary {
+    words: LanguageDictionary,
+}
+
+impl EnglishDictionary {
+    pub fn new(word_size: usize) -> Result<EnglishDictionary> {
+        let words = LanguageDictionary::new(Language::English, word_size)?;
+
+        Ok(EnglishDictionary { words })
+    }
+
+    pub fn from_file(path: &Path, word_size: usize) -> Result<EnglishDictionary> {
+        let words = LanguageDictionary::from_file(Language::English, path, word_size)?;
+
+        Ok(EnglishDictionary { words })
+    }
+
+    pub fn from_reader(reader: impl BufRead, word_size: usize) -> Result<EnglishDictionary> {
+        let words = LanguageDictionary::from_reader(Language::English, reader, word_size)?;
+
+        Ok(EnglishDictionary { words })
+    }
+
+    pub fn with_answers(
+        answers_path: &Path,
+        allowed_path: &Path,
+        word_size: usize,
+    ) -> Result<EnglishDictionary> {
+        let words = LanguageDictionary::with_answers(
+            Language::English,
+            answers_path,
+            allowed_path,
+            word_size,
+        )?;
+
+        Ok(EnglishDictionary { words })
+    }
+
+    pub fn load_frequencies(&mut self, path: &Path) -> Result<()> {
+        self.words.load_frequencies(path)
+    }
+}
+
+impl Dictionary for EnglishDictionary {
+    fn get_random_word(&self, size: usize) -> Result<String> {
+        self.words.get_random_word(size)
+    }
+
+    fn get_random_word_seeded(&self, size: usize, seed: u64) -> Result<String> {
+        self.words.get_random_word_seeded(size, seed)
+    }
+
+    fn contains_word(&self, word: &str) -> bool {
+        self.words.contains_word(word)
+    }
+
+    fn available_chars(&self) -> Vec<char> {
+        self.words.available_chars()
+    }
+
+    fn words(&self) -> Box<dyn Iterator<Item = &str> + '_> {
+        self.words.words()
+    }
+
+    fn word_size(&self) -> Option<usize> {
+        self.words.word_size()
+    }
+
+    fn get_random_word_weighted(&self, size: usize) -> Result<String> {
+        self.words.get_random_word_weighted(size)
+    }
+}
+
+/// What's wrong with a word list, by line number (starting at 1). Blank lines are fine.
+#[derive(Debug, PartialEq, Default)]
+pub struct ValidationReport {
+    pub wrong_length: Vec<(usize, String)>,
+    pub non_alphabetic: Vec<(usize, String)>,
+    // Each repeated word along with the line it first appeared on
+    pub duplicates: Vec<(usize, String, usize)>,
+}
+
+impl ValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self.wrong_length.is_empty() && self.non_alphabetic.is_empty() && self.duplicates.is_empty()
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (line, word) in self.wrong_length.iter() {
+            writeln!(
+                f,
+                "line {}: \"{}\" has {} characters",
+                line,
+                word,
+                word.chars().count()
+            )?;
+        }
+        for (line, word) in self.non_alphabetic.iter() {
+            writeln!(
+                f,
+                "line {}: \"{}\" has characters that aren't letters",
+                line, word
+            )?;
+        }
+        for (line, word, first) in self.duplicates.iter() {
+            writeln!(
+                f,
+                "line {}: \"{}\" is already on line {}",
+                line, word, first
+            )?;
+        }
+        Ok(())
+    }
+}
+
+// Checks a word list the way it'll be loaded (case folded), so words differing only in case count
+// as duplicates
+pub fn validate_dictionary(path: &Path, expected_size: usize) -> Result<ValidationReport> {
+    let file =
+        File::open(path).chain_err(|| format!("Couldn't open dictionary {}", path.display()))?;
+    let mut report = ValidationReport::default();
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    for (n, line) in io::BufReader::new(file).lines().enumerate() {
+        let line_number = n + 1;
+        let word = line?.trim().to_string();
+        if word.is_empty() {
+            continue;
+        }
+        if word.chars().count() != expected_size {
+            report.wrong_length.push((line_number, word.clone()));
+        }
+        if !word.chars().all(char::is_alphabetic) {
+            report.non_alphabetic.push((line_number, word.clone()));
+        }
+        match seen.entry(fold_case(&word)) {
+            Entry::Occupied(first) => report.duplicates.push((line_number, word, *first.get())),
+            Entry::Vacant(entry) => {
+                entry.insert(line_number);
+            }
+        }
+    }
+    Ok(report)
+}
+
+// Word sizes there's a dictionary for, i.e. with a dictionaries/<language>/<size>.txt word list
+// (or <size>.txt.gz, with the gzip feature)
+pub fn available_word_sizes(language: Language) -> Result<Vec<usize>> {
+    #[cfg(feature = "embedded")]
+    {
+        let mut sizes: Vec<usize> = EMBEDDED_DICTIONARIES
+            .iter()
+            .filter(|(directory, _, _)| *directory == language.directory())
+            .map(|(_, size, _)| *size)
+            .collect();
+        if !sizes.is_empty() {
+            sizes.sort();
+            return Ok(sizes);
+        }
+    }
+
+    let directory = format!("dictionaries/{}", language.directory());
+    let error = || format!("Error listing dictionaries in {}", directory);
+    let mut sizes: Vec<usize> = Vec::new();
+    for entry in fs::read_dir(&directory).chain_err(error)? {
+        let path = entry.chain_err(error)?.path();
+        let size: Option<usize> =
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| {
+                    #[cfg(feature = "gzip")]
+                    let name = name.strip_suffix(".gz").unwrap_or(name);
+                    name.strip_suffix(".txt")?.parse().ok()
+                });
+        sizes.extend(size);
+    }
+    sizes.sort();
+    sizes.dedup();
+    Ok(sizes)
+}
+
+/// Serves words of every size available for a language. Each size is only read the first time
+/// it's needed, and kept around afterwards.
+pub struct MultiSizeDictionary {
+    language: Language,
+    dictionaries: BTreeMap<usize, OnceCell<LanguageDictionary>>,
+}
 
-        let num_words = self.words.len();
+impl MultiSizeDictionary {
+    pub fn new(language: Language) -> Result<MultiSizeDictionary> {
+        let dictionaries = available_word_sizes(language)?
+            .into_iter()
+            .map(|size| (size, OnceCell::new()))
+            .collect();
+        Ok(MultiSizeDictionary {
+            language,
+            dictionaries,
+        })
+    }
 
-        let mut rng = rand::thread_rng();
-        let r = rng.gen_range(0..num_words);
+    pub fn sizes(&self) -> Vec<usize> {
+        self.dictionaries.keys().copied().collect()
+    }
 
-        Ok(self.words.iter().nth(r).unwrap().into())
+    fn dictionary(&self, size: usize) -> Result<&LanguageDictionary> {
+        let cell = self.dictionaries.get(&size).ok_or_else(|| {
+            format!(
+                "There are no words of {} characters (available: {})",
+                size,
+                self.sizes()
+                    .iter()
+                    .map(|s| s.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            )
+        })?;
+        if let Some(dictionary) = cell.get() {
+            return Ok(dictionary);
+        }
+        let _ = cell.set(LanguageDictionary::new(self.language, size)?);
+        Ok(cell.get().unwrap())
+    }
+}
+
+impl Dictionary for MultiSizeDictionary {
+    fn get_random_word(&self, size: usize) -> Result<String> {
+        self.dictionary(size)?.get_random_word(size)
+    }
+
+    fn get_random_word_seeded(&self, size: usize, seed: u64) -> Result<String> {
+        self.dictionary(size)?.get_random_word_seeded(size, seed)
     }
 
     fn contains_word(&self, word: &str) -> bool {
-        self.words.contains(&word.to_uppercase())
+        self.dictionary(word.chars().count())
+            .is_ok_and(|dictionary| dictionary.contains_word(word))
     }
 
     fn available_chars(&self) -> Vec<char> {
-        vec![
-            'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q',
-            'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z',
-        ]
+        self.language.alphabet()
+    }
+
+    // Goes through every size, shortest first, which means loading all of them
+    fn words(&self) -> Box<dyn Iterator<Item = &str> + '_> {
+        Box::new(
+            self.dictionaries
+                .keys()
+                .filter_map(|&size| self.dictionary(size).ok())
+                .flat_map(|dictionary| dictionary.words()),
+        )
+    }
+
+    fn word_size(&self) -> Option<usize> {
+        None
+    }
+
+    fn get_random_word_weighted(&self, size: usize) -> Result<String> {
+        self.dictionary(size)?.get_random_word_weighted(size)
+    }
+
+    // Only words of the same size can replace the word, so there's no need to load the others
+    fn closest(&self, word: &str, max_distance: usize) -> Option<String> {
+        self.dictionary(word.chars().count())
+            .ok()?
+            .closest(word, max_distance)
+    }
+}
+
+/// Keeps the built-in dictionaries loaded once they're first needed, so servers or batch runs
+/// playing many games don't read the word list again for each of them. It can be shared between
+/// threads, and so can the dictionaries it hands out.
+#[derive(Default)]
+pub struct DictionaryCache {
+    dictionaries: Mutex<HashMap<(Language, usize), Arc<CacheSlot>>>,
+}
+
+// Each dictionary is loaded under its own lock, so loading one doesn't hold up the others. A
+// failed load leaves the slot empty and the next lookup tries again.
+type CacheSlot = Mutex<Option<Arc<LanguageDictionary>>>;
+
+impl DictionaryCache {
+    pub fn new() -> DictionaryCache {
+        DictionaryCache::default()
+    }
+
+    // A dictionary is only loaded once, even if several threads ask for it at the same time
+    pub fn get(&self, language: Language, word_size: usize) -> Result<Arc<LanguageDictionary>> {
+        let slot = self.slot(language, word_size)?;
+        let mut dictionary = slot
+            .lock()
+            .map_err(|_| "The dictionary cache is poisoned")?;
+        if let Some(dictionary) = dictionary.as_ref() {
+            return Ok(Arc::clone(dictionary));
+        }
+        let loaded = Arc::new(LanguageDictionary::new(language, word_size)?);
+        *dictionary = Some(Arc::clone(&loaded));
+        Ok(loaded)
+    }
+
+    // The map is only locked long enough to find or add the slot, never while loading
+    fn slot(&self, language: Language, word_size: usize) -> Result<Arc<CacheSlot>> {
+        let mut dictionaries = self
+            .dictionaries
+            .lock()
+            .map_err(|_| "The dictionary cache is poisoned")?;
+        Ok(Arc::clone(
+            dictionaries.entry((language, word_size)).or_default(),
+        ))
+    }
+}
+
+// Lets a shared dictionary go wherever a Box<dyn Dictionary> is expected, e.g.
+// `Box::new(Arc::clone(&dictionary))` for each game played with it
+impl<D: Dictionary + ?Sized> Dictionary for Arc<D> {
+    fn get_random_word(&self, size: usize) -> Result<String> {
+        (**self).get_random_word(size)
+    }
+
+    fn get_random_word_seeded(&self, size: usize, seed: u64) -> Result<String> {
+        (**self).get_random_word_seeded(size, seed)
+    }
+
+    fn contains_word(&self, word: &str) -> bool {
+        (**self).contains_word(word)
+    }
+
+    fn available_chars(&self) -> Vec<char> {
+        (**self).available_chars()
+    }
+
+    fn words(&self) -> Box<dyn Iterator<Item = &str> + '_> {
+        (**self).words()
+    }
+
+    fn word_size(&self) -> Option<usize> {
+        (**self).word_size()
+    }
+
+    fn case_sensitive(&self) -> bool {
+        (**self).case_sensitive()
+    }
+
+    fn get_random_word_weighted(&self, size: usize) -> Result<String> {
+        (**self).get_random_word_weighted(size)
+    }
+
+    fn closest(&self, word: &str, max_distance: usize) -> Option<String> {
+        (**self).closest(word, max_distance)
+    }
+
+    fn words_with_letters(&self, must: &[char], banned: &[char]) -> Vec<String> {
+        (**self).words_with_letters(must, banned)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::game::{RoundResult, WordleGame, WordleGameImpl};
+    use super::*;
+    use std::thread;
+
+    fn vec_dictionary(words: &[&str]) -> Result<VecDictionary> {
+        let size = words.first().map_or(0, |w| w.chars().count());
+        VecDictionary::new(words.iter().map(|&w| w.into()), size)
+    }
+
+    #[test]
+    fn seeded_word_is_reproducible() {
+        let dict = EnglishDictionary::new(5).unwrap();
+        let word = dict.get_random_word_seeded(5, 42).unwrap();
+        for _ in 0..5 {
+            assert_eq!(word, dict.get_random_word_seeded(5, 42).unwrap());
+        }
+        assert_eq!(
+            word,
+            EnglishDictionary::new(5)
+                .unwrap()
+                .get_random_word_seeded(5, 42)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn random_word_is_indexed_from_sorted_list() {
+        let dict = EnglishDictionary::new(5).unwrap().words.words;
+        assert_eq!(dict.words.len(), dict.word_list.len());
+        assert!(dict.word_list.windows(2).all(|w| w[0] < w[1]));
+
+        let r = StdRng::seed_from_u64(7).gen_range(0..dict.word_list.len());
+        assert_eq!(
+            dict.word_list[r],
+            dict.get_random_word_seeded(5, 7).unwrap()
+        );
+    }
+
+    #[test]
+    fn seeded_word_does_not_depend_on_word_order() {
+        let words = ["sound", "wrong", "boost", "bound", "found", "hound"];
+        let dict = vec_dictionary(&words).unwrap();
+        let mut reversed = words;
+        reversed.reverse();
+        let reversed = vec_dictionary(&reversed).unwrap();
+        for seed in 0..20 {
+            assert_eq!(
+                dict.get_random_word_seeded(5, seed).unwrap(),
+                reversed.get_random_word_seeded(5, seed).unwrap()
+            );
+        }
+        // Pinned, so a change in how words are picked doesn't go unnoticed
+        assert_eq!("HOUND", dict.get_random_word_seeded(5, 42).unwrap());
+    }
+
+    #[test]
+    fn seeded_word_checks_size() {
+        let dict = EnglishDictionary::new(5).unwrap();
+        assert!(dict.get_random_word_seeded(6, 42).is_err());
+    }
+
+    #[test]
+    fn language_dictionary_uses_language_alphabet() {
+        let dict = LanguageDictionary::new(Language::English, 5).unwrap();
+        assert_eq!(Language::English, dict.language());
+        assert_eq!(Language::English.alphabet(), dict.available_chars());
+    }
+
+    #[test]
+    fn words_are_enumerated_in_order() {
+        let dict = vec_dictionary(&["dab", "cab", "bad"]).unwrap();
+        assert_eq!(
+            vec!["BAD", "CAB", "DAB"],
+            dict.words().collect::<Vec<&str>>()
+        );
+    }
+
+    #[test]
+    fn words_are_enumerated_through_trait_object() {
+        let dict: Box<dyn Dictionary> = Box::new(EnglishDictionary::new(5).unwrap());
+        let words: Vec<&str> = dict.words().collect();
+        assert!(!words.is_empty());
+        assert!(words
+            .iter()
+            .all(|w| w.chars().count() == 5 && dict.contains_word(w)));
+    }
+
+    #[cfg(feature = "embedded")]
+    #[test]
+    fn embedded_dictionary_matches_file() {
+        let path = Path::new("dictionaries/english/5.txt");
+        let from_file = EnglishDictionary::from_file(path, 5).unwrap();
+        let embedded = EnglishDictionary::new(5).unwrap();
+        assert!(embedded_word_list(Language::English, 5).is_some());
+        assert!(embedded.words().eq(from_file.words()));
+    }
+
+    #[test]
+    fn multi_size_dictionary_loads_sizes_lazily() {
+        let dict = MultiSizeDictionary::new(Language::English).unwrap();
+        assert!(dict.sizes().contains(&4) && dict.sizes().contains(&5));
+        assert!(dict.dictionaries.values().all(|cell| cell.get().is_none()));
+
+        assert!(dict.contains_word("sound"));
+        assert!(dict.contains_word("soup"));
+        assert!(!dict.contains_word("xxxxx"));
+        assert_eq!(4, dict.get_random_word(4).unwrap().chars().count());
+        let loaded: Vec<usize> = dict
+            .dictionaries
+            .iter()
+            .filter(|(_, cell)| cell.get().is_some())
+            .map(|(&size, _)| size)
+            .collect();
+        assert_eq!(vec![4, 5], loaded);
+    }
+
+    #[test]
+    fn multi_size_dictionary_suggests_words_of_the_same_size() {
+        let dict = MultiSizeDictionary::new(Language::English).unwrap();
+        let suggestion = dict.closest("xounds", 1).unwrap();
+        assert_eq!(6, suggestion.chars().count());
+        let loaded: Vec<usize> = dict
+            .dictionaries
+            .iter()
+            .filter(|(_, cell)| cell.get().is_some())
+            .map(|(&size, _)| size)
+            .collect();
+        assert_eq!(vec![6], loaded);
+    }
+
+    #[test]
+    fn lists_available_word_sizes() {
+        let sizes = available_word_sizes(Language::English).unwrap();
+        assert!(sizes.contains(&5));
+        assert!(!sizes.contains(&0));
+        assert!(sizes.windows(2).all(|w| w[0] < w[1]));
+        assert!(available_word_sizes(Language::Spanish)
+            .unwrap()
+            .contains(&5));
+        assert!(available_word_sizes(Language::French).is_err());
+    }
+
+    #[test]
+    fn cache_loads_each_dictionary_once() {
+        let cache = DictionaryCache::new();
+        let first = cache.get(Language::English, 5).unwrap();
+        let second = cache.get(Language::English, 5).unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+        assert!(!Arc::ptr_eq(
+            &first,
+            &cache.get(Language::English, 4).unwrap()
+        ));
+        assert!(cache.get(Language::English, 40).is_err());
+
+        let mut game =
+            WordleGameImpl::new(Box::new(Arc::clone(&first)), "sound", 6, false).unwrap();
+        assert!(matches!(game.guess_word("sound"), RoundResult::Won(..)));
+        // Held by the cache, the game, and both lookups
+        assert_eq!(4, Arc::strong_count(&first));
+    }
+
+    #[test]
+    fn cache_loads_other_dictionaries_while_one_is_loading() {
+        let cache = DictionaryCache::new();
+        // Holding a slot's lock is what a thread loading that dictionary does
+        let slot = cache.slot(Language::English, 5).unwrap();
+        let _loading = slot.lock().unwrap();
+        let dictionary = thread::scope(|scope| {
+            scope
+                .spawn(|| cache.get(Language::English, 4))
+                .join()
+                .unwrap()
+        });
+        assert_eq!(Some(4), dictionary.unwrap().word_size());
+    }
+
+    #[test]
+    fn multi_size_dictionary_rejects_missing_size() {
+        let dict = MultiSizeDictionary::new(Language::English).unwrap();
+        assert!(dict.get_random_word(100).is_err());
+        assert!(!dict.contains_word(&"A".repeat(100)));
+    }
+
+    #[test]
+    fn computes_edit_distance() {
+        assert_eq!(0, edit_distance("SOUND", "SOUND"));
+        assert_eq!(1, edit_distance("SOUND", "MOUND"));
+        assert_eq!(2, edit_distance("MOVIE", "MOVER"));
+        assert_eq!(3, edit_distance("", "ÑUS"));
+    }
+
+    #[test]
+    fn finds_closest_word() {
+        let dict = vec_dictionary(&["mover", "mower", "sound"]).unwrap();
+        assert_eq!(Some("MOVER".to_string()), dict.closest("movie", 2));
+        assert_eq!(None, dict.closest("movie", 1));
+        assert_eq!(Some("MOVER".to_string()), dict.closest("mo_er", 1));
+        // MOVER is a single edit away, but can't replace a word of 6 letters
+        assert_eq!(None, dict.closest("movers", 1));
+    }
+
+    #[test]
+    fn finds_words_with_letters() {
+        let dict = vec_dictionary(&["sound", "wrong", "boost", "bound", "mover"]).unwrap();
+        assert_eq!(
+            vec!["BOUND", "SOUND", "WRONG"],
+            dict.words_with_letters(&['n', 'O'], &[])
+        );
+        assert_eq!(vec!["BOUND"], dict.words_with_letters(&['n'], &['s', 'w']));
+        assert_eq!(vec!["MOVER"], dict.words_with_letters(&[], &['n', 't']));
+        assert!(dict.words_with_letters(&['z'], &[]).is_empty());
+        assert_eq!(5, dict.words_with_letters(&[], &[]).len());
+    }
+
+    #[test]
+    fn reports_word_size() {
+        assert_eq!(Some(3), vec_dictionary(&["cab"]).unwrap().word_size());
+        assert_eq!(Some(5), EnglishDictionary::new(5).unwrap().word_size());
+        let dict = MultiSizeDictionary::new(Language::English).unwrap();
+        assert_eq!(None, dict.word_size());
+    }
+
+    #[test]
+    fn available_chars_come_from_words() {
+        let dict = vec_dictionary(&["cab", "bad", "dab"]).unwrap();
+        assert_eq!(vec!['A', 'B', 'C', 'D'], dict.available_chars());
+    }
+
+    fn write_word_list(name: &str, contents: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("wordle-{}-{}", std::process::id(), name));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn loads_dictionary_from_file() {
+        let path = write_word_list("themed.txt", "rust\ncrab\n\nheap\n");
+        let dict = EnglishDictionary::from_file(&path, 4).unwrap();
+        assert_eq!(
+            vec!["CRAB", "HEAP", "RUST"],
+            dict.words().collect::<Vec<&str>>()
+        );
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn loads_compressed_dictionary() {
+        use flate2::write::GzEncoder;
+        use std::io::Write;
+
+        let mut encoder = GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(b"rust\ncrab\nheap\n").unwrap();
+        let path = std::env::temp_dir().join(format!("wordle-{}-4.txt.gz", std::process::id()));
+        std::fs::write(&path, encoder.finish().unwrap()).unwrap();
+
+        let dict = EnglishDictionary::from_file(&path, 4).unwrap();
+        assert_eq!(
+            vec!["CRAB", "HEAP", "RUST"],
+            dict.words().collect::<Vec<&str>>()
+        );
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn loads_dictionary_from_reader() {
+        let reader = io::Cursor::new("sound\n\nwrong \nboost\n");
+        let dict = EnglishDictionary::from_reader(reader, 5).unwrap();
+        assert_eq!(
+            vec!["BOOST", "SOUND", "WRONG"],
+            dict.words().collect::<Vec<&str>>()
+        );
+        assert!(EnglishDictionary::from_reader(io::Cursor::new("sounds\n"), 5).is_err());
+
+        // A line that can't be read fails the whole list rather than cutting it short there
+        let reader = io::Cursor::new(b"sound\nwr\xffng\nboost\n".to_vec());
+        assert!(EnglishDictionary::from_reader(reader, 5).is_err());
+    }
+
+    #[test]
+    fn picks_targets_only_from_answers() {
+        let answers = write_word_list("answers.txt", "sound\nbound\n");
+        let allowed = write_word_list("allowed.txt", "wrong\nboost\nsound\n");
+        let dict = EnglishDictionary::with_answers(&answers, &allowed, 5).unwrap();
+        assert_eq!(
+            vec!["BOOST", "BOUND", "SOUND", "WRONG"],
+            dict.words().collect::<Vec<&str>>()
+        );
+        for seed in 0..50 {
+            let word = dict.get_random_word_seeded(5, seed).unwrap();
+            assert!(word == "SOUND" || word == "BOUND");
+        }
+
+        // Words only in the allowed list are still valid guesses
+        let mut game = WordleGameImpl::new(Box::new(dict), "sound", 6, false).unwrap();
+        assert!(matches!(game.guess_word("wrong"), RoundResult::Continue(_)));
+        std::fs::remove_file(answers).unwrap();
+        std::fs::remove_file(allowed).unwrap();
+    }
+
+    #[test]
+    fn validates_word_lists() {
+        let path = write_word_list("invalid.txt", "sound\nsoun\n\nwr0ng\nboost\nSound\n");
+        let report = validate_dictionary(&path, 5).unwrap();
+        assert!(!report.is_valid());
+        assert_eq!(vec![(2, "soun".to_string())], report.wrong_length);
+        assert_eq!(vec![(4, "wr0ng".to_string())], report.non_alphabetic);
+        assert_eq!(vec![(6, "Sound".to_string(), 1)], report.duplicates);
+        assert_eq!(
+            "line 2: \"soun\" has 4 characters\n\
+             line 4: \"wr0ng\" has characters that aren't letters\n\
+             line 6: \"Sound\" is already on line 1\n",
+            report.to_string()
+        );
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn bundled_dictionaries_are_valid() {
+        for language in [Language::English, Language::Spanish] {
+            for size in available_word_sizes(language).unwrap() {
+                let path = format!("dictionaries/{}/{}.txt", language.directory(), size);
+                let report = validate_dictionary(Path::new(&path), size).unwrap();
+                assert!(report.is_valid(), "{}:\n{}", path, report);
+            }
+        }
+    }
+
+    #[test]
+    fn plays_bundled_spanish_dictionary() {
+        let dict = LanguageDictionary::new(Language::Spanish, 5).unwrap();
+        assert!(dict.available_chars().contains(&'Ñ'));
+        let mut game = WordleGameImpl::new(Box::new(dict), "sueño", 6, false).unwrap();
+
+        assert!(matches!(game.guess_word("sueñ"), RoundResult::Error(_)));
+        match game.guess_word("niños") {
+            RoundResult::Continue(status) => {
+                assert_eq!("BBYYY", status.guesses.last().unwrap().pattern())
+            }
+            _ => panic!("NIÑOS should be a valid guess"),
+        }
+        match game.guess_word("dueño") {
+            RoundResult::Continue(status) => {
+                assert_eq!("BGGGG", status.guesses.last().unwrap().pattern())
+            }
+            _ => panic!("DUEÑO should be a valid guess"),
+        }
+        assert!(matches!(game.guess_word("sueño"), RoundResult::Won(..)));
+    }
+
+    #[test]
+    fn loads_spanish_dictionary_in_nfc() {
+        let path = write_word_list("spanish.txt", "sueño\nnin\u{303}os\nzorro\n");
+        let dict = LanguageDictionary::from_file(Language::Spanish, &path, 5).unwrap();
+        assert!(dict.contains_word("NIÑOS"));
+        assert_eq!(
+            vec!['E', 'I', 'N', 'Ñ', 'O', 'R', 'S', 'U', 'Z'],
+            dict.available_chars()
+        );
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn reports_missing_dictionary_size() {
+        let e = LanguageDictionary::new(Language::English, 26)
+            .err()
+            .unwrap();
+        assert_eq!(
+            "No dictionary for word size 26 (looked in dictionaries/english/26.txt)",
+            e.to_string()
+        );
+        let e = EnglishDictionary::from_file(Path::new("missing.txt"), 5)
+            .err()
+            .unwrap();
+        assert_eq!("Couldn't open dictionary missing.txt", e.to_string());
+    }
+
+    #[test]
+    fn rejects_dictionary_file_with_wrong_word_size() {
+        let path = write_word_list("mismatch.txt", "rust\ncrabs\n");
+        let err = EnglishDictionary::from_file(&path, 4).err().unwrap();
+        assert!(err.to_string().starts_with("Error loading dictionary"));
+        assert_eq!(
+            "Word \"CRABS\" has 5 characters, expected 4",
+            err.iter().nth(1).unwrap().to_string()
+        );
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn weighted_selection_prefers_frequent_words() {
+        let mut dict = vec_dictionary(&["common", "arcane"]).unwrap();
+        let frequencies: HashMap<String, f64> =
+            HashMap::from([("COMMON".into(), 1000.0), ("ARCANE".into(), 1.0)]);
+        dict.set_frequencies(&frequencies).unwrap();
+
+        let common = (0..1000)
+            .filter(|_| dict.get_random_word_weighted(6).unwrap() == "COMMON")
+            .count();
+        assert!(common > 950);
+    }
+
+    #[test]
+    fn weighted_selection_without_frequencies_is_uniform() {
+        let dict = vec_dictionary(&["common", "arcane"]).unwrap();
+        let common = (0..1000)
+            .filter(|_| dict.get_random_word_weighted(6).unwrap() == "COMMON")
+            .count();
+        assert!(common > 350 && common < 650);
+    }
+
+    #[test]
+    fn loads_frequencies_from_file() {
+        let path = write_word_list("words.txt", "rust\ncrab\n");
+        let mut dict = EnglishDictionary::from_file(&path, 4).unwrap();
+        let frequencies = write_word_list("frequencies.txt", "rust 0\ncrab 5\n");
+        dict.load_frequencies(&frequencies).unwrap();
+        assert_eq!("CRAB", dict.get_random_word_weighted(4).unwrap());
+
+        let invalid = write_word_list("invalid.txt", "rust lots\n");
+        assert!(dict.load_frequencies(&invalid).is_err());
+        for path in [path, frequencies, invalid] {
+            std::fs::remove_file(path).unwrap();
+        }
+    }
+
+    #[test]
+    fn case_sensitive_dictionary_keeps_case() {
+        let words = ["NaCl", "NACL", "HgCl"].map(String::from);
+        let dict = VecDictionary::new_case_sensitive(words, 4).unwrap();
+        assert!(dict.case_sensitive());
+        assert_eq!(
+            vec!["HgCl", "NACL", "NaCl"],
+            dict.words().collect::<Vec<&str>>()
+        );
+        assert!(dict.contains_word("NaCl"));
+        assert!(!dict.contains_word("nacl"));
+        assert_eq!(
+            vec!['A', 'C', 'H', 'L', 'N', 'a', 'g', 'l'],
+            dict.available_chars()
+        );
+    }
+
+    #[test]
+    fn case_sensitive_dictionary_keeps_turkish_i_apart() {
+        let words = ["kıl", "kil", "KIL", "Kİl"].map(String::from);
+        let dict = VecDictionary::new_case_sensitive(words, 3).unwrap();
+        assert_eq!(4, dict.words().count());
+        assert!(dict.contains_word("kıl") && dict.contains_word("kil"));
+        assert!(!dict.contains_word("kİl"));
+        for c in ['ı', 'i', 'I', 'İ'] {
+            assert!(dict.available_chars().contains(&c));
+        }
+
+        // Folded, ı and i both become I, and only the capital İ still makes a different word
+        let words = ["kıl", "kil", "KIL", "Kİl"].map(String::from);
+        let dict = VecDictionary::new(words, 3).unwrap();
+        assert_eq!(vec!["KIL", "KİL"], dict.words().collect::<Vec<&str>>());
+    }
+
+    #[test]
+    fn symbol_dictionary_uses_the_given_alphabet() {
+        let symbols = vec!['🍎', '🍌', '🍒', '🍇'];
+        let words = ["🍎🍌🍒", "🍒🍒🍎"].map(String::from);
+        let dict = VecDictionary::with_symbols(words, 3, symbols).unwrap();
+        assert_eq!(vec!['🍇', '🍌', '🍎', '🍒'], dict.available_chars());
+        assert!(dict.contains_word("🍒🍒🍎"));
+
+        let words = ["🍎🍌🍉".to_string()];
+        assert!(VecDictionary::with_symbols(words, 3, vec!['🍎', '🍌']).is_err());
+    }
+
+    #[test]
+    fn vec_dictionary_uppercases_words() {
+        let dict = vec_dictionary(&["sound", "Wrong"]).unwrap();
+        assert!(dict.contains_word("SOUND"));
+        assert!(dict.contains_word("wrong"));
+        assert!(!dict.contains_word("boost"));
+        assert_eq!(vec!["SOUND", "WRONG"], dict.words().collect::<Vec<&str>>());
+    }
+
+    #[test]
+    fn vec_dictionary_rejects_mismatched_lengths() {
+        assert!(vec_dictionary(&["sound", "toolong"]).is_err());
+    }
+
+    #[test]
+    fn vec_dictionary_rejects_empty_word_list() {
+        assert!(VecDictionary::new(Vec::new(), 5).is_err());
     }
 }
@@ -1,5 +1,8 @@
 error_chain! {
     foreign_links {
         Io(::std::io::Error);
+        Guess(super::game::GuessError);
+        Hint(super::game::HintError);
+        Json(::serde_json::Error) #[cfg(feature = "serde")];
     }
 }
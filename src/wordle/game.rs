@@ -1,9 +1,19 @@
+use super::case::{fold_case, normalize_word, LetterCase};
+use super::clock::{Clock, Stopwatch, SystemClock};
+use super::constraints::Constraints;
 use super::dictionary::Dictionary;
 use super::errors::Result;
+use super::keyboard::KeyboardLayout;
 
-use std::collections::{HashMap, HashSet};
+use log::trace;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::time::Duration;
 
-#[derive(Debug, PartialEq, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CharStatus {
     NotInWord,
     WrongPosition,
@@ -11,263 +21,1292 @@ pub enum CharStatus {
     NotUsed,
 }
 
-#[derive(Debug, PartialEq)]
+// G (right position), Y (wrong position), B (not in the word) or . (not used yet)
+impl fmt::Display for CharStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let c = match self {
+            CharStatus::RightPosition => 'G',
+            CharStatus::WrongPosition => 'Y',
+            CharStatus::NotInWord => 'B',
+            CharStatus::NotUsed => '.',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CharAndStatus(pub char, pub CharStatus);
 
+impl CharAndStatus {
+    pub fn display_char(&self, case: LetterCase) -> String {
+        case.apply(self.0)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GuessResult {
-    _word: String,
+    word: String,
     pub chars_result: Vec<CharAndStatus>,
 }
 
 impl GuessResult {
-    fn is_won(&self) -> bool {
+    // Builds the result a guess would get from its pattern code, e.g. ("WRONG", "BBYGB")
+    pub fn from_pattern(word: &str, pattern: &str) -> Result<GuessResult> {
+        let pattern = Pattern::parse(pattern)?;
+        let word = fold_case(word);
+        if word.chars().count() != pattern.0.len() {
+            return Err(format!(
+                "Pattern \"{}\" doesn't match the length of \"{}\"",
+                pattern, word
+            )
+            .into());
+        }
+        let chars_result = word
+            .chars()
+            .zip(pattern.0)
+            .map(|(c, status)| CharAndStatus(c, status))
+            .collect();
+        Ok(GuessResult { word, chars_result })
+    }
+
+    pub fn word(&self) -> &str {
+        &self.word
+    }
+
+    /// How many times `c` appears in the target word, as far as this feedback tells. Every copy
+    /// of a letter marked as right or misplaced is in the target, so that's a lower bound; once a
+    /// copy is marked as not in the word the target has no more, and the count is exact.
+    pub fn count_in_target(&self, c: char) -> (usize, bool) {
+        let copies = self.chars_result.iter().filter(|cs| cs.0 == c);
+        let found = copies
+            .clone()
+            .filter(|cs| cs.1 == CharStatus::RightPosition || cs.1 == CharStatus::WrongPosition)
+            .count();
+        let exact = copies.clone().any(|cs| cs.1 == CharStatus::NotInWord);
+        (found, exact)
+    }
+
+    // The feedback as a code like "BBYGB"
+    pub fn pattern(&self) -> String {
+        Pattern(self.chars_result.iter().map(|cs| cs.1).collect()).to_string()
+    }
+
+    // How many letters are (right, misplaced, not in the word)
+    pub fn status_counts(&self) -> (usize, usize, usize) {
+        let count = |status| self.chars_result.iter().filter(|cs| cs.1 == status).count();
+        (
+            count(CharStatus::RightPosition),
+            count(CharStatus::WrongPosition),
+            count(CharStatus::NotInWord),
+        )
+    }
+
+    pub fn is_won(&self) -> bool {
         self.chars_result
             .iter()
             .all(|cs| cs.1 == CharStatus::RightPosition)
     }
 }
 
+// The pattern, e.g. "BBYGB"
+impl fmt::Display for GuessResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.pattern())
+    }
+}
+
+// How many times each letter appears in the word
+pub fn letter_multiset(word: &str) -> HashMap<char, usize> {
+    let mut letters = HashMap::new();
+    for c in word.chars() {
+        *letters.entry(c).or_default() += 1;
+    }
+    letters
+}
+
+/// Reads back a keyboard state, as `WordleGame::keyboard_state` writes it, for the letters in
+/// `chars`, which must be in the same order they were written in.
+pub fn parse_keyboard_state(state: &str, chars: &[char]) -> Result<Vec<CharAndStatus>> {
+    let got = state.chars().count();
+    if got != chars.len() {
+        return Err(format!(
+            "Keyboard state \"{}\" has {} letters, need {}",
+            state,
+            got,
+            chars.len()
+        )
+        .into());
+    }
+    chars
+        .iter()
+        .zip(state.chars())
+        .map(|(&c, status)| {
+            let status = match status {
+                '.' => CharStatus::NotUsed,
+                'B' => CharStatus::NotInWord,
+                'Y' => CharStatus::WrongPosition,
+                'G' => CharStatus::RightPosition,
+                _ => {
+                    return Err(format!(
+                        "Invalid character '{}' in keyboard state \"{}\"",
+                        status, state
+                    )
+                    .into())
+                }
+            };
+            Ok(CharAndStatus(c, status))
+        })
+        .collect()
+}
+
+/// The feedback for a guess, without its letters. As text, each letter is B (not in the word), Y
+/// (in the wrong position) or G (in the right position).
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Pattern(pub Vec<CharStatus>);
+
+impl Pattern {
+    pub fn parse(code: &str) -> Result<Pattern> {
+        code.chars()
+            .map(|c| match c.to_ascii_uppercase() {
+                'B' => Ok(CharStatus::NotInWord),
+                'Y' => Ok(CharStatus::WrongPosition),
+                'G' => Ok(CharStatus::RightPosition),
+                _ => Err(format!("Invalid character '{}' in pattern \"{}\"", c, code).into()),
+            })
+            .collect::<Result<Vec<CharStatus>>>()
+            .map(Pattern)
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for status in self.0.iter() {
+            let c = match status {
+                CharStatus::RightPosition => 'G',
+                CharStatus::WrongPosition => 'Y',
+                CharStatus::NotInWord | CharStatus::NotUsed => 'B',
+            };
+            write!(f, "{}", c)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GameStatus {
     pub guesses: Vec<GuessResult>,
+    // None when the game allows unlimited guesses
+    pub max_guesses: Option<usize>,
+    // Letters revealed through hints, as (position, letter)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub hints: Vec<(usize, char)>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_hints: usize,
+    // Letters the game revealed on its own after too many misses; they don't use up hints
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub mercy_hints: Vec<(usize, char)>,
 }
 
 impl GameStatus {
-    fn new_game() -> GameStatus {
+    pub(crate) fn new_game(max_guesses: Option<usize>) -> GameStatus {
         GameStatus {
             guesses: Vec::new(),
+            max_guesses,
+            hints: Vec::new(),
+            max_hints: 0,
+            mercy_hints: Vec::new(),
+        }
+    }
+
+    // Every letter revealed so far, whether the player asked for it or not
+    fn all_hints(&self) -> impl Iterator<Item = (usize, char)> + '_ {
+        self.hints.iter().chain(self.mercy_hints.iter()).copied()
+    }
+
+    // What the guesses and hints reveal about the word so far
+    pub fn constraints(&self) -> Constraints {
+        let mut constraints = Constraints::from_guesses(&self.guesses);
+        for (pos, c) in self.all_hints() {
+            if constraints.known.len() <= pos {
+                constraints.known.resize(pos + 1, None);
+            }
+            constraints.known[pos] = Some(c);
+            let present = constraints.present.entry(c).or_default();
+            present.min_count = present.min_count.max(1);
+        }
+        constraints
+    }
+
+    /// Whether a guess, not played yet, would tell nothing new about the word. A guess tells
+    /// something new when it changes the constraints: it finds a letter's position, rules out a
+    /// letter or one of its positions, or raises or caps how many copies of a letter there are.
+    /// Letters it only confirms don't count.
+    pub fn is_redundant_guess(&self, result: &GuessResult) -> bool {
+        let mut after = self.clone();
+        after.guesses.push(result.clone());
+        after.constraints() == self.constraints()
+    }
+
+    pub fn out_of_guesses(&self) -> bool {
+        self.max_guesses == Some(self.guesses.len())
+    }
+
+    pub fn guesses_remaining(&self) -> Option<usize> {
+        self.max_guesses
+            .map(|max| max.saturating_sub(self.guesses.len()))
+    }
+
+    pub fn hints_remaining(&self) -> usize {
+        self.max_hints.saturating_sub(self.hints.len())
+    }
+
+    // Letters guessed in the right position so far as (position, letter), sorted by position.
+    // A position found by several guesses is listed once.
+    pub fn known_positions(&self) -> Vec<(usize, char)> {
+        let known: BTreeMap<usize, char> = self
+            .guesses
+            .iter()
+            .flat_map(|guess| guess.chars_result.iter().enumerate())
+            .filter(|(_, cs)| cs.1 == CharStatus::RightPosition)
+            .map(|(pos, cs)| (pos, cs.0))
+            .collect();
+        known.into_iter().collect()
+    }
+
+    // For each of the word's `word_size` positions, the letters that were guessed there and marked
+    // as misplaced, so they're known not to go there. The status doesn't know the word's size
+    // before the first guess, hence the parameter.
+    pub fn position_exclusions(&self, word_size: usize) -> Vec<HashSet<char>> {
+        let mut exclusions = vec![HashSet::new(); word_size];
+        for guess in self.guesses.iter() {
+            for (excluded, cs) in exclusions.iter_mut().zip(guess.chars_result.iter()) {
+                if cs.1 == CharStatus::WrongPosition {
+                    excluded.insert(cs.0);
+                }
+            }
+        }
+        exclusions
+    }
+
+    // Positions whose letter is already known, either guessed right or revealed by a hint
+    fn revealed_positions(&self) -> HashSet<usize> {
+        self.known_positions()
+            .into_iter()
+            .chain(self.all_hints())
+            .map(|(pos, _)| pos)
+            .collect()
+    }
+
+    pub fn emoji_grid(&self) -> String {
+        let won = self.guesses.last().is_some_and(|g| g.is_won());
+        let score = if won {
+            self.guesses.len().to_string()
+        } else {
+            "X".into()
+        };
+
+        let max_guesses = match self.max_guesses {
+            Some(max) => max.to_string(),
+            None => "∞".into(),
+        };
+        let mut header = format!("Wordle {}/{}", score, max_guesses);
+        match self.all_hints().count() {
+            0 => {}
+            1 => header.push_str(" (1 hint)"),
+            n => header.push_str(&format!(" ({} hints)", n)),
+        }
+        let mut lines = vec![header, "".into()];
+        for guess in self.guesses.iter() {
+            lines.push(
+                guess
+                    .chars_result
+                    .iter()
+                    .map(|cs| match cs.1 {
+                        CharStatus::RightPosition => '🟩',
+                        CharStatus::WrongPosition => '🟨',
+                        CharStatus::NotInWord | CharStatus::NotUsed => '⬛',
+                    })
+                    .collect(),
+            );
+        }
+        lines.join("\n")
+    }
+}
+
+// How a finished game went, owning all its data so it can be kept around freely
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GameOutcome {
+    pub won: bool,
+    pub word: String,
+    pub guesses: Vec<String>,
+    pub num_guesses: usize,
+}
+
+impl GameOutcome {
+    pub(crate) fn new(status: &GameStatus, word: &str) -> GameOutcome {
+        let guesses: Vec<String> = status.guesses.iter().map(|g| g.word.clone()).collect();
+        GameOutcome {
+            won: status.guesses.last().is_some_and(|g| g.is_won()),
+            word: word.into(),
+            num_guesses: guesses.len(),
+            guesses,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct SavedGame<S> {
+    word: String,
+    status: S,
+    chars_status: HashMap<char, CharStatus>,
+    hard_mode: bool,
+    #[serde(default)]
+    duplicate_policy: DuplicatePolicy,
+    #[serde(default)]
+    reject_repeat_guesses: bool,
+    #[serde(default)]
+    mercy_after: Option<usize>,
+    #[serde(default)]
+    allow_any_word: bool,
+}
+
+/// How a guess is scored. Both policies count repeated letters the same way: a copy of a letter
+/// is misplaced only while the target has copies of it left that weren't guessed in place.
+///
+/// - `Wordle` tells the status of every guessed letter, handing out the misplaced marks to
+///   repeated letters left to right.
+/// - `Mastermind` only tells how many letters are right and how many are misplaced, not which
+///   ones: the statuses come sorted, right ones first, so they no longer line up with the
+///   letters. The keyboard isn't updated and hard mode has nothing to enforce.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DuplicatePolicy {
+    #[default]
+    Wordle,
+    Mastermind,
+}
+
+impl DuplicatePolicy {
+    pub fn score(&self, target: &str, guess: &str) -> std::result::Result<GuessResult, GuessError> {
+        let mut result = WordleGameImpl::guess_result(target, guess)?;
+        if *self == DuplicatePolicy::Mastermind {
+            let mut statuses: Vec<CharStatus> = result.chars_result.iter().map(|cs| cs.1).collect();
+            statuses.sort_by_key(|status| match status {
+                CharStatus::RightPosition => 0,
+                CharStatus::WrongPosition => 1,
+                CharStatus::NotInWord | CharStatus::NotUsed => 2,
+            });
+            for (cs, status) in result.chars_result.iter_mut().zip(statuses) {
+                cs.1 = status;
+            }
+        }
+        Ok(result)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum GuessError {
+    WrongLength {
+        word: String,
+        expected: usize,
+        got: usize,
+    },
+    NotInDictionary {
+        word: String,
+        suggestion: Option<String>,
+    },
+    HardModeViolation(HardModeViolation),
+    InvalidCharacters(Vec<char>),
+    RepeatedGuess(String),
+    GameAlreadyOver,
+}
+
+/// The letter a hard mode guess left out, so it can be described in any language
+#[derive(Debug, PartialEq, Clone)]
+pub enum HardModeViolation {
+    /// A letter found in the right position (counting from 0) has to stay there
+    MissingLetterAt { position: usize, letter: char },
+    /// A letter known to be in the word has to be used somewhere
+    MissingLetter(char),
+}
+
+impl fmt::Display for HardModeViolation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HardModeViolation::MissingLetterAt { position, letter } => {
+                write!(f, "{} letter must be {}", ordinal(position + 1), letter)
+            }
+            HardModeViolation::MissingLetter(letter) => write!(f, "Guess must contain {}", letter),
         }
     }
 }
 
+// How many edits away a word can be to be suggested in place of one that's not in the dictionary
+pub(crate) const MAX_SUGGESTION_DISTANCE: usize = 2;
+
+impl fmt::Display for GuessError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GuessError::WrongLength {
+                word,
+                expected,
+                got,
+            } => {
+                write!(f, "\"{}\" has {} letters, need {}", word, got, expected)
+            }
+            GuessError::NotInDictionary { word, suggestion } => {
+                write!(f, "Word \"{}\" is not in the dictionary!", word)?;
+                if let Some(suggestion) = suggestion {
+                    write!(f, " Did you mean \"{}\"?", suggestion)?;
+                }
+                Ok(())
+            }
+            GuessError::HardModeViolation(violation) => write!(f, "{}", violation),
+            GuessError::InvalidCharacters(chars) => {
+                let chars: Vec<String> = chars.iter().map(|c| c.to_string()).collect();
+                write!(f, "Word can't contain {}", chars.join(", "))
+            }
+            GuessError::RepeatedGuess(word) => write!(f, "\"{}\" was already guessed!", word),
+            GuessError::GameAlreadyOver => write!(f, "The game is already over!"),
+        }
+    }
+}
+
+impl std::error::Error for GuessError {}
+
+/// Why a letter couldn't be revealed
+#[derive(Debug, PartialEq, Clone)]
+pub enum HintError {
+    NoHintsLeft,
+    AllLettersKnown,
+    // The mode has no word to reveal letters from, like Absurdle
+    Unavailable,
+    GameAlreadyOver,
+}
+
+impl fmt::Display for HintError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HintError::NoHintsLeft => write!(f, "No hints left"),
+            HintError::AllLettersKnown => write!(f, "Every letter is already known"),
+            HintError::Unavailable => write!(f, "Hints are not available in this mode"),
+            HintError::GameAlreadyOver => write!(f, "The game is already over!"),
+        }
+    }
+}
+
+impl std::error::Error for HintError {}
+
+/// The outcome of a guess. It borrows the game's status, which costs nothing but ties it to the
+/// game until it's dropped; use `into_owned` to keep it around longer, e.g. to collect every
+/// round of several games, at the cost of copying the status.
 pub enum RoundResult<'a> {
-    Error(String),
+    Error(GuessError),
     Won(&'a GameStatus, String),
     Lost(&'a GameStatus, String),
     Continue(&'a GameStatus),
 }
 
+impl RoundResult<'_> {
+    pub fn into_owned(self) -> OwnedRoundResult {
+        match self {
+            RoundResult::Error(e) => OwnedRoundResult::Error(e),
+            RoundResult::Won(status, word) => OwnedRoundResult::Won(status.clone(), word),
+            RoundResult::Lost(status, word) => OwnedRoundResult::Lost(status.clone(), word),
+            RoundResult::Continue(status) => OwnedRoundResult::Continue(status.clone()),
+        }
+    }
+}
+
+// A RoundResult with its own snapshot of the status, as it was right after the guess
+#[derive(Debug, PartialEq, Clone)]
+pub enum OwnedRoundResult {
+    Error(GuessError),
+    Won(GameStatus, String),
+    Lost(GameStatus, String),
+    Continue(GameStatus),
+}
+
 pub trait WordleGame {
     fn guess_word<'a>(&'a mut self, word: &str) -> RoundResult<'a>;
-    fn max_guesses(&self) -> usize;
+    // None when guesses are unlimited
+    fn max_guesses(&self) -> Option<usize>;
+    fn guesses_used(&self) -> usize;
+    fn guesses_remaining(&self) -> Option<usize>;
     fn chars_status(&self) -> Vec<CharAndStatus>;
+    fn status(&self) -> &GameStatus;
+    // Time spent on the game so far, or until it was won or lost
+    fn elapsed(&self) -> Duration;
+    // Reveals the letter at a position that isn't known yet, using up one hint
+    fn use_hint(&mut self) -> std::result::Result<(usize, char), HintError>;
+    // None until the game is won or lost
+    fn outcome(&self) -> Option<GameOutcome>;
+
+    // Plays the guesses in order without any user interaction, stopping as soon as the game is
+    // won or lost. Each entry is the feedback for a guess, or the reason it was rejected.
+    fn play_sequence(
+        &mut self,
+        guesses: &[&str],
+    ) -> Vec<std::result::Result<GuessResult, GuessError>> {
+        let mut results = Vec::new();
+        for guess in guesses.iter() {
+            match self.guess_word(guess) {
+                RoundResult::Error(e) => results.push(Err(e)),
+                RoundResult::Continue(status) => {
+                    results.push(Ok(status.guesses.last().unwrap().clone()))
+                }
+                RoundResult::Won(status, _) | RoundResult::Lost(status, _) => {
+                    results.push(Ok(status.guesses.last().unwrap().clone()));
+                    break;
+                }
+            }
+        }
+        results
+    }
+
+    // Points for a won game: a base amount, plus a bonus for every guess left over, minus a small
+    // penalty for every letter tried that isn't in the word. Games not won score nothing.
+    fn score(&self) -> u32 {
+        const BASE_POINTS: u32 = 100;
+        const UNUSED_GUESS_BONUS: u32 = 20;
+        const ABSENT_LETTER_PENALTY: u32 = 1;
+
+        if !self.outcome().is_some_and(|outcome| outcome.won) {
+            return 0;
+        }
+        // Guesses left over only count when there was a limit to begin with
+        let bonus = UNUSED_GUESS_BONUS * self.guesses_remaining().unwrap_or(0) as u32;
+        let penalty =
+            ABSENT_LETTER_PENALTY * self.letters_by_status(CharStatus::NotInWord).len() as u32;
+        (BASE_POINTS + bonus).saturating_sub(penalty)
+    }
+
+    fn letters_by_status(&self, status: CharStatus) -> Vec<char> {
+        let mut letters: Vec<char> = self
+            .chars_status()
+            .into_iter()
+            .filter(|cs| cs.1 == status)
+            .map(|cs| cs.0)
+            .collect();
+        letters.sort();
+        letters
+    }
+
+    // One character per letter, in the order of chars_status, e.g. "......B......GY..B....B..."
+    // for the English alphabet after guessing WRONG for SOUND. See CharStatus for the characters.
+    fn keyboard_state(&self) -> String {
+        self.chars_status()
+            .iter()
+            .map(|cs| cs.1.to_string())
+            .collect()
+    }
+
+    // Letters of the alphabet that the layout doesn't have go in an extra last row
+    fn keyboard_layout(&self, layout: KeyboardLayout) -> Vec<Vec<CharAndStatus>> {
+        let chars_status: HashMap<char, CharStatus> = self
+            .chars_status()
+            .into_iter()
+            .map(|CharAndStatus(c, status)| (c, status))
+            .collect();
+
+        let layout_rows = layout.rows();
+        let mut rows: Vec<Vec<CharAndStatus>> = layout_rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .filter_map(|c| chars_status.get(c).map(|&s| CharAndStatus(*c, s)))
+                    .collect()
+            })
+            .collect();
+
+        let extra: Vec<CharAndStatus> = self
+            .chars_status()
+            .into_iter()
+            .filter(|cs| !layout_rows.iter().any(|row| row.contains(&cs.0)))
+            .collect();
+        if !extra.is_empty() {
+            rows.push(extra);
+        }
+
+        rows
+    }
 }
 
 pub struct WordleGameImpl {
     dictionary: Box<dyn Dictionary>,
     word: String,
     status: GameStatus,
-    max_guesses: usize,
     chars_status: HashMap<char, CharStatus>,
+    hard_mode: bool,
+    duplicate_policy: DuplicatePolicy,
+    reject_repeat_guesses: bool,
+    mercy_after: Option<usize>,
+    allow_any_word: bool,
+    stopwatch: Stopwatch,
 }
 
 impl WordleGameImpl {
+    // A game needs at least one guess; 6 is the classic limit. There's no upper bound, but for no
+    // limit at all use with_unlimited_guesses.
     pub fn new(
         dictionary: Box<dyn Dictionary>,
         word: &str,
         max_guesses: usize,
+        hard_mode: bool,
     ) -> Result<WordleGameImpl> {
-        let word = word.to_uppercase();
+        if max_guesses == 0 {
+            return Err("A game must allow at least one guess".into());
+        }
+        let got = word.chars().count();
+        match dictionary.word_size() {
+            Some(expected) if got != expected => {
+                return Err(GuessError::WrongLength {
+                    word: word.into(),
+                    expected,
+                    got,
+                }
+                .into());
+            }
+            _ => {}
+        }
+        if !dictionary.contains_word(word) {
+            return Err(format!("Word \"{}\" is not in the dictionary", word).into());
+        }
+        Ok(WordleGameImpl::new_unchecked(
+            dictionary,
+            word,
+            max_guesses,
+            hard_mode,
+        ))
+    }
+
+    // Skips checking that the word is in the dictionary, mostly useful for testing. Note that
+    // such a word can only be guessed if the dictionary happens to contain it.
+    pub fn new_unchecked(
+        dictionary: Box<dyn Dictionary>,
+        word: &str,
+        max_guesses: usize,
+        hard_mode: bool,
+    ) -> WordleGameImpl {
+        let word = normalize_word(word, dictionary.case_sensitive());
         let chars_status: HashMap<char, CharStatus> = dictionary
             .available_chars()
             .iter()
             .map(|&c| (c, CharStatus::NotUsed))
             .collect();
 
-        Ok(WordleGameImpl {
+        WordleGameImpl {
             dictionary,
             word,
-            status: GameStatus::new_game(),
-            max_guesses,
+            status: GameStatus::new_game(Some(max_guesses)),
             chars_status,
-        })
-    }
-
-    fn guess_result(target_word: &str, guess_word: &str) -> GuessResult {
-        assert!(target_word.len() == guess_word.len());
-
-        let mut positions_map: HashMap<char, HashSet<usize>> = HashMap::new();
-        for (pos, c) in target_word.chars().enumerate() {
-            positions_map.entry(c).or_default().insert(pos);
+            hard_mode,
+            duplicate_policy: DuplicatePolicy::default(),
+            reject_repeat_guesses: false,
+            mercy_after: None,
+            allow_any_word: false,
+            stopwatch: Stopwatch::new(Box::new(SystemClock)),
         }
+    }
 
-        let mut processed_positions: HashSet<usize> = HashSet::new();
-
-        let mut chars_result: Vec<CharAndStatus> = guess_word
-            .chars()
-            .map(|c| CharAndStatus(c, CharStatus::NotInWord))
-            .collect();
-
-        // First, remove all positions that match
-        target_word
-            .chars()
-            .zip(guess_word.chars())
-            .enumerate()
-            .filter_map(
-                |(pos, (src, guess))| {
-                    if src == guess {
-                        Some((pos, src))
-                    } else {
-                        None
-                    }
-                },
-            )
-            .for_each(|(pos, c)| {
-                assert!(chars_result[pos].0 == c);
-                chars_result[pos].1 = CharStatus::RightPosition;
-                // Now remove the right position from the list of positions map
-                positions_map.get_mut(&c).unwrap().remove(&pos);
-                processed_positions.insert(pos);
-            });
+    pub fn with_hints(mut self, max_hints: usize) -> WordleGameImpl {
+        self.status.max_hints = max_hints;
+        self
+    }
 
-        guess_word
-            .chars()
-            .enumerate()
-            .filter(|(pos, _)| !processed_positions.contains(&pos))
-            .for_each(|(pos, src_char)| {
-                let char_status: CharStatus = match positions_map.get_mut(&src_char) {
-                    None => CharStatus::NotInWord,
-                    Some(ref positions) if positions.is_empty() => CharStatus::NotInWord,
-                    Some(ref mut positions) => {
-                        // We already processed all the "correct" positions
-                        assert!(!positions.contains(&pos));
-                        // Remove some element from the list of positions. It doesn't really matter
-                        // which one.
-                        let first_element: usize = *positions.iter().next().unwrap();
-                        positions.remove(&first_element);
-                        CharStatus::WrongPosition
-                    }
-                };
+    // Lets the player keep guessing until they find the word, so the game can't be lost
+    pub fn with_unlimited_guesses(mut self) -> WordleGameImpl {
+        self.status.max_guesses = None;
+        self
+    }
 
-                chars_result[pos].1 = char_status;
-            });
+    pub fn with_duplicate_policy(mut self, duplicate_policy: DuplicatePolicy) -> WordleGameImpl {
+        self.duplicate_policy = duplicate_policy;
+        self
+    }
 
-        GuessResult {
-            _word: guess_word.into(),
-            chars_result,
-        }
+    // Guessing a word a second time is an error instead of a wasted turn
+    pub fn with_repeat_guesses_rejected(mut self) -> WordleGameImpl {
+        self.reject_repeat_guesses = true;
+        self
     }
-}
 
-impl WordleGame for WordleGameImpl {
-    fn max_guesses(&self) -> usize {
-        self.max_guesses
+    // Guesses only need the right length and letters from the dictionary's alphabet, not to be
+    // words it knows. The word to guess still comes from the dictionary.
+    pub fn with_any_word_allowed(mut self) -> WordleGameImpl {
+        self.allow_any_word = true;
+        self
     }
 
-    fn chars_status(&self) -> Vec<CharAndStatus> {
-        self.dictionary
-            .available_chars()
-            .iter()
-            .map(|&c| CharAndStatus(c, *self.chars_status.get(&c).unwrap()))
-            .collect()
+    // Once this many guesses have missed the word, the game reveals a letter the player hasn't
+    // found yet, for free. It only does it once per game.
+    pub fn with_mercy_after(mut self, failed_guesses: usize) -> WordleGameImpl {
+        self.mercy_after = Some(failed_guesses);
+        self
     }
 
-    fn guess_word<'a>(&'a mut self, word: &str) -> RoundResult<'a> {
-        let word = word.to_uppercase();
+    // Times the game with the given clock instead of the system one
+    pub fn with_clock(mut self, clock: Box<dyn Clock>) -> WordleGameImpl {
+        self.stopwatch = Stopwatch::new(clock);
+        self
+    }
 
-        let num_guesses = self.status.guesses.len();
-        if num_guesses == self.max_guesses {
-            return RoundResult::Lost(&self.status, self.word.clone());
-        } else if word.len() != self.word.len() {
-            return RoundResult::Error(format!("Word must be {} characters!", self.word.len()));
-        } else if !self.dictionary.contains_word(&word) {
-            return RoundResult::Error(format!("Word \"{}\" is not in the dictionary!", word));
+    // A letter keeps the most telling status any of its copies got, so the order of the guesses,
+    // or of the copies within one, doesn't matter. A surplus copy marked as not in the word never
+    // hides another copy being in the word.
+    pub(crate) fn merge_chars_status(
+        chars_status: &mut HashMap<char, CharStatus>,
+        result: &GuessResult,
+    ) {
+        fn rank(status: CharStatus) -> u8 {
+            match status {
+                CharStatus::NotUsed => 0,
+                CharStatus::NotInWord => 1,
+                CharStatus::WrongPosition => 2,
+                CharStatus::RightPosition => 3,
+            }
         }
 
-        let result = WordleGameImpl::guess_result(&self.word, &word);
-
-        // Update internal cache
         for cs in result.chars_result.iter() {
             let CharAndStatus(guessed_char, guess_status) = *cs;
 
-            self.chars_status.entry(guessed_char).and_modify(|entry| {
-                let new_status: CharStatus = match (*entry, guess_status) {
-                    (CharStatus::NotUsed, s) => s,
-                    (CharStatus::RightPosition, _) => CharStatus::RightPosition,
-                    (_, CharStatus::RightPosition) => CharStatus::RightPosition,
-                    (CharStatus::WrongPosition, _) => CharStatus::WrongPosition,
-                    (_, s) => s,
-                };
-                *entry = new_status;
+            chars_status.entry(guessed_char).and_modify(|entry| {
+                if rank(guess_status) > rank(*entry) {
+                    *entry = guess_status;
+                }
             });
         }
+    }
 
-        let won: bool = result.is_won();
-        self.status.guesses.push(result);
+    // Starts over with a new word, keeping the dictionary and settings. The word has to be as
+    // long as the one before, since that's what the guesses are checked against.
+    pub fn reset(&mut self, new_word: &str) -> Result<()> {
+        let expected = self.word.chars().count();
+        let got = new_word.chars().count();
+        if got != expected {
+            return Err(GuessError::WrongLength {
+                word: new_word.into(),
+                expected,
+                got,
+            }
+            .into());
+        }
+        if !self.dictionary.contains_word(new_word) {
+            return Err(format!("Word \"{}\" is not in the dictionary", new_word).into());
+        }
+        self.word = normalize_word(new_word, self.dictionary.case_sensitive());
+        let max_hints = self.status.max_hints;
+        self.status = GameStatus::new_game(self.status.max_guesses);
+        self.status.max_hints = max_hints;
+        self.recompute_chars_status();
+        self.stopwatch.restart();
+        Ok(())
+    }
 
-        if won {
-            RoundResult::Won(&self.status, self.word.clone())
-        } else if self.status.guesses.len() == self.max_guesses {
-            return RoundResult::Lost(&self.status, self.word.clone());
-        } else {
-            RoundResult::Continue(&self.status)
+    // Takes back the last guess, as if it had never been made
+    pub fn undo(&mut self) -> Result<()> {
+        if self.status.guesses.pop().is_none() {
+            return Err("There are no guesses to undo".into());
+        }
+        if self
+            .mercy_after
+            .is_some_and(|after| self.status.guesses.len() < after)
+        {
+            self.status.mercy_hints.clear();
         }
+        self.recompute_chars_status();
+        self.stopwatch.resume();
+        Ok(())
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::super::dictionary::EnglishDictionary;
-    use super::*;
+    // Statuses can't be rolled back one guess at a time (a letter may have been upgraded by
+    // several of them), so they're rebuilt from the remaining guesses and hints
+    fn recompute_chars_status(&mut self) {
+        self.chars_status
+            .values_mut()
+            .for_each(|status| *status = CharStatus::NotUsed);
+        for guess in self.status.guesses.clone() {
+            self.track_letters(&guess);
+        }
+        for (_, c) in self.status.all_hints().collect::<Vec<_>>() {
+            self.reveal(c);
+        }
+    }
 
-    fn set_up_game(word: &str) -> WordleGameImpl {
-        let dict = EnglishDictionary::new(word.len()).unwrap();
-        WordleGameImpl::new(Box::new(dict), word, 3).unwrap()
+    // Mastermind feedback can't be told apart letter by letter, so it says nothing about them
+    fn track_letters(&mut self, result: &GuessResult) {
+        if self.duplicate_policy == DuplicatePolicy::Wordle {
+            WordleGameImpl::merge_chars_status(&mut self.chars_status, result);
+        }
     }
 
-    #[test]
-    fn fails_after_specified_tries() {
-        let word: String = "sound".into();
-        let mut game = set_up_game(&word);
-        let r1 = game.guess_word("wrong");
-        assert!(matches!(r1, RoundResult::Continue(_)));
-        let r2 = game.guess_word("wrong");
-        assert!(matches!(r2, RoundResult::Continue(_)));
-        let r3 = game.guess_word("wrong");
-        assert!(matches!(r3, RoundResult::Lost(_, _)));
-        if let RoundResult::Lost(_, result_word) = r3 {
-            assert_eq!(result_word.to_lowercase(), word.to_lowercase());
-        } else {
-            unreachable!();
+    // Plays a guess on the player's behalf, like a fixed opening word. It goes through the same
+    // checks as any other guess, and a rejected one doesn't count.
+    pub fn auto_guess(&mut self, word: &str) -> std::result::Result<GuessResult, GuessError> {
+        match self.guess_word(word) {
+            RoundResult::Error(e) => Err(e),
+            RoundResult::Won(status, _)
+            | RoundResult::Lost(status, _)
+            | RoundResult::Continue(status) => Ok(status.guesses.last().unwrap().clone()),
         }
     }
 
-    #[test]
-    fn suceeds_correctly() {
-        let word: String = "sound".into();
-        let mut game = set_up_game(&word);
-        let r1 = game.guess_word("wrong");
-        assert!(matches!(r1, RoundResult::Continue(_)));
-        let r2 = game.guess_word(&word);
-        assert!(matches!(r2, RoundResult::Won(_, _)));
+    // The word to guess, for tests and debugging tools only; it's left out of normal builds
+    #[cfg(any(test, feature = "cheat"))]
+    pub fn answer(&self) -> &str {
+        &self.word
     }
 
-    #[test]
-    fn fails_with_wrong_number_of_letters() {
-        let mut game = set_up_game("sound");
-        let r1 = game.guess_word("toomanyletters");
-        assert!(matches!(r1, RoundResult::Error(_)));
+    pub fn dictionary(&self) -> &dyn Dictionary {
+        self.dictionary.as_ref()
     }
 
-    #[test]
-    fn reports_letters_1() {
-        let word: String = "sound".into();
-        let mut game = set_up_game(&word);
-        let guess = game.guess_word("wrong");
-        assert!(matches!(guess, RoundResult::Continue(_)));
+    #[cfg(feature = "serde")]
+    pub fn save(&self) -> Result<String> {
+        let saved = SavedGame {
+            word: self.word.clone(),
+            status: &self.status,
+            chars_status: self.chars_status.clone(),
+            hard_mode: self.hard_mode,
+            duplicate_policy: self.duplicate_policy,
+            reject_repeat_guesses: self.reject_repeat_guesses,
+            mercy_after: self.mercy_after,
+            allow_any_word: self.allow_any_word,
+        };
+        Ok(serde_json::to_string(&saved)?)
+    }
 
-        if let RoundResult::Continue(status) = guess {
-            assert_eq!(1, status.guesses.len());
-            let guess_result = status.guesses.first().unwrap();
-            let chars_result = &guess_result.chars_result;
-            assert_eq!(CharAndStatus('W', CharStatus::NotInWord), chars_result[0]);
-            assert_eq!(CharAndStatus('R', CharStatus::NotInWord), chars_result[1]);
-            assert_eq!(
-                CharAndStatus('O', CharStatus::WrongPosition),
-                chars_result[2]
-            );
-            assert_eq!(
-                CharAndStatus('N', CharStatus::RightPosition),
-                chars_result[3]
+    #[cfg(feature = "serde")]
+    pub fn load(dictionary: Box<dyn Dictionary>, saved: &str) -> Result<WordleGameImpl> {
+        let saved: SavedGame<GameStatus> = serde_json::from_str(saved)?;
+
+        let word_size = saved.word.chars().count();
+        match dictionary.word_size() {
+            Some(dictionary_size) if dictionary_size != word_size => {
+                return Err(format!(
+                    "Saved word has {} characters but the dictionary has words of {} characters",
+                    word_size, dictionary_size
+                )
+                .into());
+            }
+            _ => {}
+        }
+
+        let mut game = match saved.status.max_guesses {
+            Some(max_guesses) => {
+                WordleGameImpl::new(dictionary, &saved.word, max_guesses, saved.hard_mode)?
+            }
+            None => WordleGameImpl::new(dictionary, &saved.word, 1, saved.hard_mode)?
+                .with_unlimited_guesses(),
+        }
+        .with_duplicate_policy(saved.duplicate_policy);
+        game.reject_repeat_guesses = saved.reject_repeat_guesses;
+        game.mercy_after = saved.mercy_after;
+        game.allow_any_word = saved.allow_any_word;
+
+        // Replay the saved guesses rather than trusting them, so a tampered save can't smuggle
+        // in words or feedback the game would never have produced
+        for guess in saved.status.guesses.iter() {
+            if !game.allow_any_word && !game.dictionary.contains_word(&guess.word) {
+                return Err(
+                    format!("Saved guess \"{}\" is not in the dictionary", guess.word).into(),
+                );
+            }
+            let result = game.duplicate_policy.score(&game.word, &guess.word)?;
+            if result != *guess {
+                return Err(
+                    format!("Saved feedback for \"{}\" is inconsistent", guess.word).into(),
+                );
+            }
+            game.track_letters(&result);
+            game.status.guesses.push(result);
+        }
+
+        if game
+            .status
+            .max_guesses
+            .is_some_and(|max| game.status.guesses.len() > max)
+        {
+            return Err("Saved game has more guesses than allowed".into());
+        }
+
+        let target: Vec<char> = game.word.chars().collect();
+        for (pos, c) in saved.status.all_hints() {
+            if target.get(pos) != Some(&c) {
+                return Err("Saved hints are inconsistent with the word".into());
+            }
+            game.reveal(c);
+        }
+        if saved.status.hints.len() > saved.status.max_hints {
+            return Err("Saved game has more hints than allowed".into());
+        }
+        if saved.status.mercy_hints.len() > usize::from(saved.mercy_after.is_some()) {
+            return Err("Saved game has more mercy hints than allowed".into());
+        }
+        game.status.hints = saved.status.hints;
+        game.status.max_hints = saved.status.max_hints;
+        game.status.mercy_hints = saved.status.mercy_hints;
+        if game.chars_status != saved.chars_status {
+            return Err("Saved letter statuses are inconsistent with the guesses".into());
+        }
+
+        Ok(game)
+    }
+
+    // The first letter of the word the player doesn't know yet
+    fn next_hint(&self) -> Option<(usize, char)> {
+        let revealed = match self.duplicate_policy {
+            DuplicatePolicy::Wordle => self.status.revealed_positions(),
+            DuplicatePolicy::Mastermind => self.status.all_hints().map(|(pos, _)| pos).collect(),
+        };
+        self.word
+            .chars()
+            .enumerate()
+            .find(|(pos, _)| !revealed.contains(pos))
+    }
+
+    // Only called while the game goes on, so every guess so far has missed
+    fn give_mercy_hint(&mut self) {
+        if self.mercy_after != Some(self.status.guesses.len())
+            || !self.status.mercy_hints.is_empty()
+        {
+            return;
+        }
+        if let Some(hint) = self.next_hint() {
+            self.status.mercy_hints.push(hint);
+            self.reveal(hint.1);
+        }
+    }
+
+    fn reveal(&mut self, c: char) {
+        self.chars_status
+            .entry(c)
+            .and_modify(|status| *status = CharStatus::RightPosition);
+    }
+
+    fn is_over(&self) -> bool {
+        let won = self.status.guesses.last().is_some_and(|g| g.is_won());
+        won || self.status.out_of_guesses()
+    }
+
+    // In hard mode, every revealed hint must be used in subsequent guesses
+    fn hard_mode_violation(&self, word: &str) -> Option<HardModeViolation> {
+        let guess: Vec<char> = word.chars().collect();
+
+        for previous in self.status.guesses.iter() {
+            for (pos, cs) in previous.chars_result.iter().enumerate() {
+                if cs.1 == CharStatus::RightPosition && guess.get(pos) != Some(&cs.0) {
+                    return Some(HardModeViolation::MissingLetterAt {
+                        position: pos,
+                        letter: cs.0,
+                    });
+                }
+            }
+        }
+
+        for previous in self.status.guesses.iter() {
+            let mut required: HashMap<char, usize> = HashMap::new();
+            for cs in previous.chars_result.iter() {
+                if cs.1 == CharStatus::RightPosition || cs.1 == CharStatus::WrongPosition {
+                    *required.entry(cs.0).or_default() += 1;
+                }
+            }
+
+            let mut required: Vec<(char, usize)> = required.into_iter().collect();
+            required.sort();
+            for (c, count) in required {
+                if guess.iter().filter(|&&g| g == c).count() < count {
+                    return Some(HardModeViolation::MissingLetter(c));
+                }
+            }
+        }
+
+        None
+    }
+
+    // The feedback the guess would get, or why it would be rejected, without playing it
+    pub(crate) fn check_guess(&self, word: &str) -> std::result::Result<GuessResult, GuessError> {
+        let word = normalize_word(word, self.dictionary.case_sensitive());
+
+        if self.is_over() {
+            return Err(GuessError::GameAlreadyOver);
+        }
+
+        let expected = self.word.chars().count();
+        let got = word.chars().count();
+        let invalid = invalid_chars(&word, &self.dictionary.available_chars());
+        if got != expected {
+            return Err(GuessError::WrongLength {
+                word,
+                expected,
+                got,
+            });
+        } else if !invalid.is_empty() {
+            return Err(GuessError::InvalidCharacters(invalid));
+        } else if !self.allow_any_word && !self.dictionary.contains_word(&word) {
+            let suggestion = self.dictionary.closest(&word, MAX_SUGGESTION_DISTANCE);
+            return Err(GuessError::NotInDictionary { word, suggestion });
+        } else if self.reject_repeat_guesses
+            && self.status.guesses.iter().any(|guess| guess.word == word)
+        {
+            return Err(GuessError::RepeatedGuess(word));
+        } else if self.hard_mode && self.duplicate_policy == DuplicatePolicy::Wordle {
+            if let Some(violation) = self.hard_mode_violation(&word) {
+                return Err(GuessError::HardModeViolation(violation));
+            }
+        }
+
+        self.duplicate_policy.score(&self.word, &word)
+    }
+
+    pub(crate) fn guess_result(
+        target_word: &str,
+        guess_word: &str,
+    ) -> std::result::Result<GuessResult, GuessError> {
+        let expected = target_word.chars().count();
+        let got = guess_word.chars().count();
+        if expected != got {
+            return Err(GuessError::WrongLength {
+                word: guess_word.into(),
+                expected,
+                got,
+            });
+        }
+
+        let mut chars_result: Vec<CharAndStatus> = guess_word
+            .chars()
+            .map(|c| CharAndStatus(c, CharStatus::NotInWord))
+            .collect();
+
+        // Letters in the right position are taken out first, so only the copies left over can
+        // mark other letters as misplaced, from left to right
+        let mut remaining = letter_multiset(target_word);
+        for (cs, target_char) in chars_result.iter_mut().zip(target_word.chars()) {
+            if cs.0 == target_char {
+                cs.1 = CharStatus::RightPosition;
+                *remaining.get_mut(&target_char).unwrap() -= 1;
+            }
+        }
+        for cs in chars_result
+            .iter_mut()
+            .filter(|cs| cs.1 != CharStatus::RightPosition)
+        {
+            if let Some(count) = remaining.get_mut(&cs.0).filter(|count| **count > 0) {
+                *count -= 1;
+                cs.1 = CharStatus::WrongPosition;
+            }
+        }
+
+        Ok(GuessResult {
+            word: guess_word.into(),
+            chars_result,
+        })
+    }
+}
+
+impl WordleGame for WordleGameImpl {
+    fn max_guesses(&self) -> Option<usize> {
+        self.status.max_guesses
+    }
+
+    fn guesses_used(&self) -> usize {
+        self.status.guesses.len()
+    }
+
+    fn guesses_remaining(&self) -> Option<usize> {
+        self.status.guesses_remaining()
+    }
+
+    fn status(&self) -> &GameStatus {
+        &self.status
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.stopwatch.elapsed()
+    }
+
+    fn outcome(&self) -> Option<GameOutcome> {
+        if self.is_over() {
+            Some(GameOutcome::new(&self.status, &self.word))
+        } else {
+            None
+        }
+    }
+
+    fn use_hint(&mut self) -> std::result::Result<(usize, char), HintError> {
+        if self.is_over() {
+            return Err(HintError::GameAlreadyOver);
+        }
+        if self.status.hints_remaining() == 0 {
+            return Err(HintError::NoHintsLeft);
+        }
+
+        let hint = self.next_hint().ok_or(HintError::AllLettersKnown)?;
+        self.status.hints.push(hint);
+        self.reveal(hint.1);
+        Ok(hint)
+    }
+
+    fn chars_status(&self) -> Vec<CharAndStatus> {
+        self.dictionary
+            .available_chars()
+            .iter()
+            .map(|&c| CharAndStatus(c, *self.chars_status.get(&c).unwrap()))
+            .collect()
+    }
+
+    fn guess_word<'a>(&'a mut self, word: &str) -> RoundResult<'a> {
+        let result = match self.check_guess(word) {
+            Ok(result) => result,
+            Err(e) => return RoundResult::Error(e),
+        };
+        trace!("Scored guess {} as {}", word, result);
+
+        // Update internal cache
+        self.track_letters(&result);
+
+        let won: bool = result.is_won();
+        self.status.guesses.push(result);
+        if self.is_over() {
+            self.stopwatch.stop();
+        }
+
+        if won {
+            RoundResult::Won(&self.status, self.word.clone())
+        } else if self.status.out_of_guesses() {
+            RoundResult::Lost(&self.status, self.word.clone())
+        } else {
+            self.give_mercy_hint();
+            RoundResult::Continue(&self.status)
+        }
+    }
+}
+
+/// Computes the feedback the game would give for `guess` when the hidden word is `target`. Letters
+/// in the right place are marked first; the remaining occurrences of each letter in the target then
+/// mark repeated guess letters as misplaced, left to right, and any surplus as not in the word.
+///
+/// Both words must have the same number of characters, otherwise `GuessError::WrongLength` is
+/// returned.
+pub fn score_guess(
+    target: &str,
+    guess: &str,
+) -> std::result::Result<Vec<CharAndStatus>, GuessError> {
+    Ok(WordleGameImpl::guess_result(target, guess)?.chars_result)
+}
+
+// Characters of the word that aren't in the alphabet, each listed once in order of appearance
+pub(crate) fn invalid_chars(word: &str, alphabet: &[char]) -> Vec<char> {
+    let mut invalid: Vec<char> = Vec::new();
+    for c in word.chars() {
+        if !alphabet.contains(&c) && !invalid.contains(&c) {
+            invalid.push(c);
+        }
+    }
+    invalid
+}
+
+fn ordinal(n: usize) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::clock::tests::FakeClock;
+    use super::super::dictionary::{EnglishDictionary, MultiSizeDictionary, VecDictionary};
+    use super::super::errors::ErrorKind;
+    use super::super::language::Language;
+    use super::*;
+
+    fn set_up_game(word: &str) -> WordleGameImpl {
+        let dict = EnglishDictionary::new(word.len()).unwrap();
+        WordleGameImpl::new(Box::new(dict), word, 3, false).unwrap()
+    }
+
+    fn set_up_hard_game(word: &str) -> WordleGameImpl {
+        let dict = EnglishDictionary::new(word.len()).unwrap();
+        WordleGameImpl::new(Box::new(dict), word, 3, true).unwrap()
+    }
+
+    #[test]
+    fn fails_after_specified_tries() {
+        let word: String = "sound".into();
+        let mut game = set_up_game(&word);
+        let r1 = game.guess_word("wrong");
+        assert!(matches!(r1, RoundResult::Continue(_)));
+        let r2 = game.guess_word("wrong");
+        assert!(matches!(r2, RoundResult::Continue(_)));
+        let r3 = game.guess_word("wrong");
+        assert!(matches!(r3, RoundResult::Lost(_, _)));
+        if let RoundResult::Lost(_, result_word) = r3 {
+            assert_eq!(result_word.to_lowercase(), word.to_lowercase());
+        } else {
+            unreachable!();
+        }
+    }
+
+    #[test]
+    fn suceeds_correctly() {
+        let word: String = "sound".into();
+        let mut game = set_up_game(&word);
+        let r1 = game.guess_word("wrong");
+        assert!(matches!(r1, RoundResult::Continue(_)));
+        let r2 = game.guess_word(&word);
+        assert!(matches!(r2, RoundResult::Won(_, _)));
+    }
+
+    #[test]
+    fn fails_with_wrong_number_of_letters() {
+        let mut game = set_up_game("sound");
+        let r1 = game.guess_word("toomanyletters");
+        assert!(matches!(
+            r1,
+            RoundResult::Error(GuessError::WrongLength {
+                expected: 5,
+                got: 14,
+                ..
+            })
+        ));
+
+        match game.guess_word("soun") {
+            RoundResult::Error(e) => assert_eq!("\"SOUN\" has 4 letters, need 5", e.to_string()),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn reports_letters_1() {
+        let word: String = "sound".into();
+        let mut game = set_up_game(&word);
+        let guess = game.guess_word("wrong");
+        assert!(matches!(guess, RoundResult::Continue(_)));
+
+        if let RoundResult::Continue(status) = guess {
+            assert_eq!(1, status.guesses.len());
+            let guess_result = status.guesses.first().unwrap();
+            assert_eq!("WRONG", guess_result.word());
+            let chars_result = &guess_result.chars_result;
+            assert_eq!(CharAndStatus('W', CharStatus::NotInWord), chars_result[0]);
+            assert_eq!(CharAndStatus('R', CharStatus::NotInWord), chars_result[1]);
+            assert_eq!(
+                CharAndStatus('O', CharStatus::WrongPosition),
+                chars_result[2]
+            );
+            assert_eq!(
+                CharAndStatus('N', CharStatus::RightPosition),
+                chars_result[3]
             );
             assert_eq!(CharAndStatus('G', CharStatus::NotInWord), chars_result[4]);
         } else {
@@ -325,4 +1364,1001 @@ mod tests {
             unreachable!();
         }
     }
+
+    #[test]
+    fn plays_sequence_until_game_ends() {
+        let mut game = set_up_game("sound");
+        let results = game.play_sequence(&["wrong", "zzzzz", "sound", "boost"]);
+        assert_eq!(3, results.len());
+        assert_eq!("WRONG", results[0].as_ref().unwrap().word());
+        assert!(matches!(
+            results[1],
+            Err(GuessError::NotInDictionary { .. })
+        ));
+        assert!(results[2].as_ref().unwrap().is_won());
+        assert_eq!(2, game.guesses_used());
+    }
+
+    #[test]
+    fn reset_starts_a_new_game() {
+        let mut game = set_up_game("sound");
+        game.guess_word("wrong");
+        game.guess_word("wrong");
+        assert!(matches!(game.guess_word("wrong"), RoundResult::Lost(_, _)));
+
+        game.reset("boost").unwrap();
+        assert_eq!("BOOST", game.answer());
+        assert_eq!(0, game.guesses_used());
+        assert_eq!(Some(3), game.guesses_remaining());
+        assert!(game
+            .chars_status()
+            .iter()
+            .all(|cs| cs.1 == CharStatus::NotUsed));
+        assert!(matches!(game.guess_word("boost"), RoundResult::Won(_, ref w) if w == "BOOST"));
+    }
+
+    #[test]
+    fn reset_rejects_unknown_word() {
+        let mut game = set_up_game("sound");
+        game.guess_word("wrong");
+        assert!(game.reset("zzzzz").is_err());
+        assert_eq!(1, game.guesses_used());
+    }
+
+    #[test]
+    fn reset_rejects_word_of_another_size() {
+        let dict = MultiSizeDictionary::new(Language::English).unwrap();
+        let mut game = WordleGameImpl::new(Box::new(dict), "sound", 6, false).unwrap();
+        game.guess_word("wrong");
+        assert!(game.dictionary().contains_word("sounds"));
+        assert!(matches!(
+            game.reset("sounds").unwrap_err().kind(),
+            ErrorKind::Guess(GuessError::WrongLength {
+                expected: 5,
+                got: 6,
+                ..
+            })
+        ));
+        assert_eq!(1, game.guesses_used());
+        assert_eq!("SOUND", game.answer());
+    }
+
+    #[test]
+    fn measures_time_until_game_ends() {
+        let clock = FakeClock::new();
+        let mut game = set_up_game("sound").with_clock(Box::new(clock.clone()));
+        clock.advance(Duration::from_secs(30));
+        game.guess_word("wrong");
+        assert_eq!(Duration::from_secs(30), game.elapsed());
+
+        clock.advance(Duration::from_secs(12));
+        assert!(matches!(game.guess_word("sound"), RoundResult::Won(_, _)));
+        clock.advance(Duration::from_secs(100));
+        assert_eq!(Duration::from_secs(42), game.elapsed());
+    }
+
+    #[test]
+    fn hints_reveal_unknown_letters() {
+        let mut game = set_up_game("sound").with_hints(2);
+        game.guess_word("wrong");
+        assert_eq!((0, 'S'), game.use_hint().unwrap());
+        // N was already guessed in its right position
+        assert_eq!((1, 'O'), game.use_hint().unwrap());
+        assert_eq!(Err(HintError::NoHintsLeft), game.use_hint());
+        assert_eq!(
+            CharAndStatus('O', CharStatus::RightPosition),
+            game.chars_status()[14]
+        );
+        if let RoundResult::Won(status, _) = game.guess_word("sound") {
+            assert!(status.emoji_grid().starts_with("Wordle 2/3 (2 hints)\n"));
+        } else {
+            unreachable!();
+        }
+    }
+
+    #[test]
+    fn hints_are_disabled_by_default() {
+        let mut game = set_up_game("sound");
+        assert!(game.use_hint().is_err());
+        assert!(game.status().hints.is_empty());
+    }
+
+    #[test]
+    fn accepts_any_word_without_dictionary_check() {
+        let mut game = set_up_game("sound");
+        assert!(matches!(
+            game.guess_word("zzzzz"),
+            RoundResult::Error(GuessError::NotInDictionary { .. })
+        ));
+
+        let mut game = set_up_game("sound").with_any_word_allowed();
+        assert!(matches!(game.guess_word("zzzzz"), RoundResult::Continue(_)));
+        assert!(matches!(
+            game.guess_word("zzzz"),
+            RoundResult::Error(GuessError::WrongLength { .. })
+        ));
+        assert!(matches!(
+            game.guess_word("zzzz1"),
+            RoundResult::Error(GuessError::InvalidCharacters(_))
+        ));
+        assert!(matches!(game.guess_word("sound"), RoundResult::Won(..)));
+    }
+
+    #[test]
+    fn mercy_hint_appears_after_configured_misses() {
+        let dict = EnglishDictionary::new(5).unwrap();
+        let mut game = WordleGameImpl::new(Box::new(dict), "sound", 6, false)
+            .unwrap()
+            .with_hints(1)
+            .with_mercy_after(2);
+        game.guess_word("wrong");
+        assert!(game.status().mercy_hints.is_empty());
+
+        // BOOST finds the O and leaves the S misplaced, which the hint then places
+        game.guess_word("boost");
+        assert_eq!(vec![(0, 'S')], game.status().mercy_hints);
+        assert!(game
+            .chars_status()
+            .contains(&CharAndStatus('S', CharStatus::RightPosition)));
+        assert_eq!(1, game.status().hints_remaining());
+        assert_eq!(Some('S'), game.status().constraints().known[0]);
+
+        game.guess_word("bound");
+        assert_eq!(1, game.status().mercy_hints.len());
+
+        game.undo().unwrap();
+        game.undo().unwrap();
+        assert!(game.status().mercy_hints.is_empty());
+        assert!(game
+            .chars_status()
+            .contains(&CharAndStatus('S', CharStatus::NotUsed)));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn saves_and_loads_hints() {
+        let mut game = set_up_game("sound").with_hints(3);
+        game.guess_word("wrong");
+        game.use_hint().unwrap();
+        let saved = game.save().unwrap();
+
+        let dict = EnglishDictionary::new(5).unwrap();
+        let loaded = WordleGameImpl::load(Box::new(dict), &saved).unwrap();
+        assert_eq!(vec![(0, 'S')], loaded.status().hints);
+        assert_eq!(2, loaded.status().hints_remaining());
+
+        let dict = EnglishDictionary::new(5).unwrap();
+        let tampered = saved.replace("[0,\"S\"]", "[0,\"X\"]");
+        assert!(WordleGameImpl::load(Box::new(dict), &tampered).is_err());
+    }
+
+    #[test]
+    fn constraints_include_hints() {
+        let mut game = set_up_game("sound").with_hints(1);
+        game.guess_word("wrong");
+        game.use_hint().unwrap();
+        let constraints = game.status().constraints();
+        assert_eq!(
+            vec![Some('S'), None, None, Some('N'), None],
+            constraints.known
+        );
+        assert!(constraints.present.contains_key(&'S'));
+    }
+
+    #[test]
+    fn encodes_feedback_as_pattern() {
+        let result = WordleGameImpl::guess_result("SOUND", "WRONG").unwrap();
+        assert_eq!("BBYGB", result.pattern());
+        assert_eq!(result, GuessResult::from_pattern("wrong", "bbygb").unwrap());
+    }
+
+    #[test]
+    fn displays_statuses_and_results() {
+        let statuses = [
+            CharStatus::RightPosition,
+            CharStatus::WrongPosition,
+            CharStatus::NotInWord,
+            CharStatus::NotUsed,
+        ];
+        let codes: Vec<String> = statuses.iter().map(|s| s.to_string()).collect();
+        assert_eq!(vec!["G", "Y", "B", "."], codes);
+
+        let result = WordleGameImpl::guess_result("SOUND", "WRONG").unwrap();
+        assert_eq!("BBYGB", result.to_string());
+    }
+
+    #[test]
+    fn counts_letters_of_a_word() {
+        let letters = letter_multiset("BOOKKEEPER");
+        assert_eq!(
+            HashMap::from([('B', 1), ('O', 2), ('K', 2), ('E', 3), ('P', 1), ('R', 1)]),
+            letters
+        );
+        assert!(letter_multiset("").is_empty());
+    }
+
+    #[test]
+    fn counts_each_status() {
+        let result = WordleGameImpl::guess_result("SOUND", "WRONG").unwrap();
+        assert_eq!((1, 1, 3), result.status_counts());
+        let result = WordleGameImpl::guess_result("SOUND", "SOUND").unwrap();
+        assert_eq!((5, 0, 0), result.status_counts());
+    }
+
+    #[test]
+    fn parses_patterns() {
+        assert_eq!(
+            Pattern(vec![
+                CharStatus::RightPosition,
+                CharStatus::WrongPosition,
+                CharStatus::NotInWord
+            ]),
+            Pattern::parse("GYB").unwrap()
+        );
+        assert!(Pattern::parse("GYX").is_err());
+        assert!(GuessResult::from_pattern("WRONG", "BBYG").is_err());
+    }
+
+    #[test]
+    fn undo_restores_previous_letter_statuses() {
+        let mut game = set_up_game("sound");
+        game.guess_word("wrong");
+        game.guess_word("boost");
+        // O at position 2 is only known to be right because of BOOST
+        assert_eq!(
+            CharAndStatus('O', CharStatus::RightPosition),
+            game.chars_status()[14]
+        );
+
+        game.undo().unwrap();
+        assert_eq!(1, game.guesses_used());
+        assert_eq!(
+            CharAndStatus('O', CharStatus::WrongPosition),
+            game.chars_status()[14]
+        );
+        assert_eq!(
+            CharAndStatus('B', CharStatus::NotUsed),
+            game.chars_status()[1]
+        );
+
+        game.undo().unwrap();
+        assert!(game
+            .chars_status()
+            .iter()
+            .all(|cs| cs.1 == CharStatus::NotUsed));
+        assert!(game.undo().is_err());
+    }
+
+    #[test]
+    fn undo_reopens_finished_game() {
+        let mut game = set_up_game("sound");
+        assert!(matches!(game.guess_word("sound"), RoundResult::Won(_, _)));
+        game.undo().unwrap();
+        assert!(matches!(game.guess_word("wrong"), RoundResult::Continue(_)));
+    }
+
+    #[test]
+    fn reports_outcome_once_game_ends() {
+        let mut game = set_up_game("sound");
+        game.guess_word("wrong");
+        assert_eq!(None, game.outcome());
+        game.guess_word("sound");
+        assert_eq!(
+            Some(GameOutcome {
+                won: true,
+                word: "SOUND".into(),
+                guesses: vec!["WRONG".into(), "SOUND".into()],
+                num_guesses: 2,
+            }),
+            game.outcome()
+        );
+
+        let mut game = set_up_game("sound");
+        for _ in 0..3 {
+            game.guess_word("wrong");
+        }
+        let outcome = game.outcome().unwrap();
+        assert!(!outcome.won);
+        assert_eq!(3, outcome.num_guesses);
+    }
+
+    #[test]
+    fn plays_spanish_words_with_enye() {
+        let words = ["sueño", "dueño", "niños", "piñas"];
+        let dict = VecDictionary::new(words.iter().map(|&w| w.into()), 5).unwrap();
+        assert!(dict.available_chars().contains(&'Ñ'));
+        let mut game = WordleGameImpl::new(Box::new(dict), "SUEÑO", 3, false).unwrap();
+
+        // Ñ takes two bytes, or two characters when decomposed, but it's a single letter
+        assert!(matches!(
+            game.guess_word("sueñ"),
+            RoundResult::Error(GuessError::WrongLength {
+                expected: 5,
+                got: 4,
+                ..
+            })
+        ));
+        if let RoundResult::Continue(status) = game.guess_word("nin\u{303}os") {
+            assert_eq!("BBYYY", status.guesses[0].pattern());
+            assert_eq!('Ñ', status.guesses[0].chars_result[2].0);
+        } else {
+            unreachable!();
+        }
+        if let RoundResult::Continue(status) = game.guess_word("dueño") {
+            assert_eq!("BGGGG", status.guesses[1].pattern());
+        } else {
+            unreachable!();
+        }
+        assert!(matches!(game.guess_word("sueño"), RoundResult::Won(_, _)));
+    }
+
+    #[test]
+    fn scores_faster_wins_higher() {
+        let play = |guesses: &[&str]| {
+            let dict = EnglishDictionary::new(5).unwrap();
+            let mut game = WordleGameImpl::new(Box::new(dict), "sound", 6, false).unwrap();
+            game.play_sequence(guesses);
+            game.score()
+        };
+        // W, R and G aren't in the word
+        assert_eq!(100 + 4 * 20 - 3, play(&["wrong", "sound"]));
+        assert_eq!(
+            100 - 3,
+            play(&["wrong", "wrong", "wrong", "wrong", "wrong", "sound"])
+        );
+        assert_eq!(0, play(&["wrong"; 6]));
+        assert_eq!(0, play(&["wrong"]));
+    }
+
+    #[test]
+    fn marks_repeated_letters_right_first_then_misplaced() {
+        let cases = [
+            // One O in the target, two in the guess: only the one in place counts
+            ("SOUND", "BOOST", "BGBYB"),
+            // The E already in place uses up the only other one
+            ("THOSE", "GEESE", "BBBGG"),
+            // Two Bs in the target: one in place, the other one misplaced
+            ("ABBEY", "BABES", "YYGGB"),
+            // Two Ls in the target, three in the guess
+            ("SKILL", "LOLLY", "YBBGB"),
+            ("EERIE", "THERE", "BBYYG"),
+            ("SPEED", "EERIE", "YYBBB"),
+        ];
+        for (target, guess, pattern) in cases {
+            let result = WordleGameImpl::guess_result(target, guess).unwrap();
+            assert_eq!(pattern, result.pattern(), "{} against {}", guess, target);
+        }
+    }
+
+    #[test]
+    fn counts_letters_in_target() {
+        let result = WordleGameImpl::guess_result("SOUND", "BOOST").unwrap();
+        assert_eq!((1, true), result.count_in_target('O'));
+        assert_eq!((1, false), result.count_in_target('S'));
+        assert_eq!((0, true), result.count_in_target('B'));
+        assert_eq!((0, false), result.count_in_target('Z'));
+
+        let result = WordleGameImpl::guess_result("SKILL", "LOLLY").unwrap();
+        assert_eq!((2, true), result.count_in_target('L'));
+    }
+
+    #[test]
+    fn reports_repeated_letters_deterministically() {
+        for _ in 0..10 {
+            let result = WordleGameImpl::guess_result("THERE", "EERIE").unwrap();
+            assert_eq!(
+                vec![
+                    CharAndStatus('E', CharStatus::WrongPosition),
+                    CharAndStatus('E', CharStatus::NotInWord),
+                    CharAndStatus('R', CharStatus::WrongPosition),
+                    CharAndStatus('I', CharStatus::NotInWord),
+                    CharAndStatus('E', CharStatus::RightPosition),
+                ],
+                result.chars_result
+            );
+        }
+    }
+
+    #[test]
+    fn hard_mode_rejects_unused_right_position() {
+        let mut game = set_up_hard_game("sound");
+        assert!(matches!(game.guess_word("wrong"), RoundResult::Continue(_)));
+        let r2 = game.guess_word("boost");
+        assert!(matches!(r2, RoundResult::Error(ref e) if e.to_string() == "4th letter must be N"));
+        let violation = HardModeViolation::MissingLetterAt {
+            position: 3,
+            letter: 'N',
+        };
+        assert!(
+            matches!(r2, RoundResult::Error(GuessError::HardModeViolation(ref v)) if *v == violation)
+        );
+    }
+
+    #[test]
+    fn hard_mode_rejects_unused_wrong_position() {
+        let mut game = set_up_hard_game("sound");
+        assert!(matches!(game.guess_word("wrong"), RoundResult::Continue(_)));
+        let r2 = game.guess_word("bland");
+        assert!(matches!(r2, RoundResult::Error(ref e) if e.to_string() == "Guess must contain O"));
+    }
+
+    #[test]
+    fn hard_mode_accepts_guess_using_hints() {
+        let mut game = set_up_hard_game("sound");
+        assert!(matches!(game.guess_word("wrong"), RoundResult::Continue(_)));
+        assert!(matches!(game.guess_word("mound"), RoundResult::Continue(_)));
+    }
+
+    #[test]
+    fn ordinals() {
+        assert_eq!("1st", ordinal(1));
+        assert_eq!("2nd", ordinal(2));
+        assert_eq!("3rd", ordinal(3));
+        assert_eq!("4th", ordinal(4));
+        assert_eq!("11th", ordinal(11));
+        assert_eq!("22nd", ordinal(22));
+    }
+
+    #[test]
+    fn renders_emoji_grid() {
+        let mut game = set_up_game("sound");
+        game.guess_word("wrong");
+        game.guess_word("boost");
+        if let RoundResult::Won(status, _) = game.guess_word("sound") {
+            assert_eq!(
+                "Wordle 3/3\n\n⬛⬛🟨🟩⬛\n⬛🟩⬛🟨⬛\n🟩🟩🟩🟩🟩",
+                status.emoji_grid()
+            );
+        } else {
+            unreachable!();
+        }
+    }
+
+    #[test]
+    fn renders_emoji_grid_for_lost_game() {
+        let mut game = set_up_game("sound");
+        game.guess_word("wrong");
+        game.guess_word("wrong");
+        if let RoundResult::Lost(status, _) = game.guess_word("wrong") {
+            assert!(status.emoji_grid().starts_with("Wordle X/3\n"));
+        } else {
+            unreachable!();
+        }
+    }
+
+    #[test]
+    fn duplicate_policies_differ_on_repeated_letters() {
+        let statuses = |policy: DuplicatePolicy| -> String {
+            let result = policy.score("ALLOY", "LLAMA").unwrap();
+            assert_eq!("LLAMA", result.word());
+            result.pattern()
+        };
+        // The first L is misplaced, the second in place, and only the first A is misplaced
+        assert_eq!("YGYBB", statuses(DuplicatePolicy::Wordle));
+        // One letter right and two misplaced, without telling which
+        assert_eq!("GYYBB", statuses(DuplicatePolicy::Mastermind));
+    }
+
+    #[test]
+    fn mastermind_policy_leaves_letters_unknown() {
+        let mut game = set_up_game("alloy").with_duplicate_policy(DuplicatePolicy::Mastermind);
+        if let RoundResult::Continue(status) = game.guess_word("llama") {
+            assert_eq!("GYYBB", status.guesses[0].pattern());
+        } else {
+            unreachable!();
+        }
+        assert!(game
+            .chars_status()
+            .iter()
+            .all(|cs| cs.1 == CharStatus::NotUsed));
+        assert!(matches!(game.guess_word("alloy"), RoundResult::Won(_, _)));
+    }
+
+    #[test]
+    fn lists_known_positions() {
+        let mut game = set_up_game("sound");
+        assert!(game.status().known_positions().is_empty());
+        game.guess_word("wrong");
+        game.guess_word("boost");
+        assert_eq!(vec![(1, 'O'), (3, 'N')], game.status().known_positions());
+        // Guessing O and N right again doesn't list them twice
+        game.guess_word("bound");
+        assert_eq!(
+            vec![(1, 'O'), (2, 'U'), (3, 'N'), (4, 'D')],
+            game.status().known_positions()
+        );
+    }
+
+    #[test]
+    fn detects_redundant_guesses() {
+        let mut game = set_up_game("sound");
+        let wrong = WordleGameImpl::guess_result("SOUND", "WRONG").unwrap();
+        assert!(!game.status().is_redundant_guess(&wrong));
+
+        game.guess_word("wrong");
+        assert!(game.status().is_redundant_guess(&wrong));
+        // Another word is just as redundant if it only tries known letters where they're known
+        // to be, or not to be
+        let shuffled = WordleGameImpl::guess_result("SOUND", "GWONR").unwrap();
+        assert!(game.status().is_redundant_guess(&shuffled));
+        // Moving N tells it's not in the last position
+        let grown = WordleGameImpl::guess_result("SOUND", "GROWN").unwrap();
+        assert!(!game.status().is_redundant_guess(&grown));
+        let boost = WordleGameImpl::guess_result("SOUND", "BOOST").unwrap();
+        assert!(!game.status().is_redundant_guess(&boost));
+    }
+
+    #[test]
+    fn lists_position_exclusions() {
+        let mut game = set_up_game("sound");
+        let exclusions = game.status().position_exclusions(5);
+        assert_eq!(vec![HashSet::new(); 5], exclusions);
+        game.guess_word("often");
+        let exclusions = game.status().position_exclusions(5);
+        assert_eq!(5, exclusions.len());
+        assert_eq!(HashSet::from(['O']), exclusions[0]);
+        assert_eq!(HashSet::from(['N']), exclusions[4]);
+        assert!(exclusions[1].is_empty());
+
+        game.guess_word("nodus");
+        let exclusions = game.status().position_exclusions(5);
+        assert_eq!(HashSet::from(['O', 'N']), exclusions[0]);
+    }
+
+    #[test]
+    fn unlimited_game_is_never_lost() {
+        let mut game = set_up_game("sound").with_unlimited_guesses();
+        for _ in 0..10 {
+            assert!(matches!(game.guess_word("wrong"), RoundResult::Continue(_)));
+        }
+        assert_eq!((None, None), (game.max_guesses(), game.guesses_remaining()));
+        assert!(game.outcome().is_none());
+        if let RoundResult::Won(status, _) = game.guess_word("sound") {
+            assert!(status.emoji_grid().starts_with("Wordle 11/∞\n"));
+        } else {
+            unreachable!();
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serializes_game_status() {
+        let mut game = set_up_game("sound");
+        game.guess_word("wrong");
+        game.guess_word("boost");
+        if let RoundResult::Won(status, _) = game.guess_word("sound") {
+            let json = serde_json::to_string(status).unwrap();
+            let deserialized: GameStatus = serde_json::from_str(&json).unwrap();
+            assert_eq!(*status, deserialized);
+            assert_eq!("BOOST", deserialized.guesses[1].word);
+        } else {
+            unreachable!();
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    fn set_up_saved_game() -> String {
+        let mut game = set_up_game("sound");
+        game.guess_word("wrong");
+        game.guess_word("boost");
+        game.save().unwrap()
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn saves_and_loads_game() {
+        let saved = set_up_saved_game();
+        let dict = EnglishDictionary::new(5).unwrap();
+        let mut game = WordleGameImpl::load(Box::new(dict), &saved).unwrap();
+        assert_eq!(2, game.status.guesses.len());
+        assert_eq!(
+            CharAndStatus('O', CharStatus::RightPosition),
+            game.chars_status()[14]
+        );
+        assert!(matches!(game.guess_word("sound"), RoundResult::Won(_, _)));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn load_rejects_wrong_word_size() {
+        let saved = set_up_saved_game();
+        let dict = EnglishDictionary::new(6).unwrap();
+        assert!(WordleGameImpl::load(Box::new(dict), &saved).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn load_rejects_tampered_guesses() {
+        let saved = set_up_saved_game().replace("BOOST", "BXXST");
+        let dict = EnglishDictionary::new(5).unwrap();
+        assert!(WordleGameImpl::load(Box::new(dict), &saved).is_err());
+    }
+
+    #[test]
+    fn fails_with_word_not_in_dictionary() {
+        let mut game = set_up_game("sound");
+        let r1 = game.guess_word("xxxxx");
+        assert!(
+            matches!(r1, RoundResult::Error(GuessError::NotInDictionary { ref word, .. }) if word == "XXXXX")
+        );
+    }
+
+    #[test]
+    fn suggests_closest_word() {
+        let words = vec!["sound".into(), "wrong".into(), "boost".into()];
+        let dict = VecDictionary::new(words, 5).unwrap();
+        let mut game = WordleGameImpl::new(Box::new(dict), "wrong", 3, false).unwrap();
+        let r1 = game.guess_word("souns");
+        assert!(matches!(
+            r1,
+            RoundResult::Error(ref e) if e.to_string() == "Word \"SOUNS\" is not in the dictionary! Did you mean \"SOUND\"?"
+        ));
+        let r2 = game.guess_word("ggggg");
+        assert!(matches!(
+            r2,
+            RoundResult::Error(GuessError::NotInDictionary {
+                suggestion: None,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn fails_with_characters_outside_alphabet() {
+        let mut game = set_up_game("sound");
+        let r1 = game.guess_word("12345");
+        assert!(matches!(
+            r1,
+            RoundResult::Error(GuessError::InvalidCharacters(ref chars)) if chars.len() == 5
+        ));
+        let r2 = game.guess_word("so-n!");
+        assert!(matches!(
+            r2,
+            RoundResult::Error(ref e) if e.to_string() == "Word can't contain -, !"
+        ));
+        assert_eq!(0, game.guesses_used());
+    }
+
+    #[test]
+    fn fails_after_game_is_over() {
+        let mut game = set_up_game("sound");
+        assert!(matches!(game.guess_word("sound"), RoundResult::Won(_, _)));
+        assert!(matches!(
+            game.guess_word("sound"),
+            RoundResult::Error(GuessError::GameAlreadyOver)
+        ));
+    }
+
+    #[test]
+    fn counts_remaining_guesses() {
+        let mut game = set_up_game("sound");
+        assert_eq!(
+            (0, Some(3)),
+            (game.guesses_used(), game.guesses_remaining())
+        );
+        game.guess_word("wrong");
+        assert_eq!(
+            (1, Some(2)),
+            (game.guesses_used(), game.guesses_remaining())
+        );
+        game.guess_word("toomanyletters");
+        assert_eq!(
+            (1, Some(2)),
+            (game.guesses_used(), game.guesses_remaining())
+        );
+        game.guess_word("wrong");
+        assert_eq!(
+            (2, Some(1)),
+            (game.guesses_used(), game.guesses_remaining())
+        );
+        assert!(matches!(game.guess_word("wrong"), RoundResult::Lost(_, _)));
+        assert_eq!(
+            (3, Some(0)),
+            (game.guesses_used(), game.guesses_remaining())
+        );
+    }
+
+    #[test]
+    fn partitions_letters_by_status() {
+        let mut game = set_up_game("sound");
+        game.guess_word("wrong");
+        assert_eq!(vec!['O'], game.letters_by_status(CharStatus::WrongPosition));
+        assert_eq!(vec!['N'], game.letters_by_status(CharStatus::RightPosition));
+
+        game.guess_word("boost");
+        assert_eq!(
+            vec!['B', 'G', 'R', 'T', 'W'],
+            game.letters_by_status(CharStatus::NotInWord)
+        );
+        assert_eq!(vec!['S'], game.letters_by_status(CharStatus::WrongPosition));
+        assert_eq!(
+            vec!['N', 'O'],
+            game.letters_by_status(CharStatus::RightPosition)
+        );
+        assert_eq!(18, game.letters_by_status(CharStatus::NotUsed).len());
+    }
+
+    #[test]
+    fn encodes_keyboard_state() {
+        let mut game = set_up_game("sound");
+        assert_eq!(".".repeat(26), game.keyboard_state());
+
+        game.guess_word("wrong");
+        assert_eq!("......B......GY..B....B...", game.keyboard_state());
+
+        // O goes from misplaced to found, S is newly misplaced
+        game.guess_word("boost");
+        let state = game.keyboard_state();
+        assert_eq!(".B....B......GG..BYB..B...", state);
+
+        let chars = game.dictionary().available_chars();
+        assert_eq!(
+            game.chars_status(),
+            parse_keyboard_state(&state, &chars).unwrap()
+        );
+        assert!(parse_keyboard_state(&state[1..], &chars).is_err());
+        assert!(parse_keyboard_state(&state.replace('G', "X"), &chars).is_err());
+    }
+
+    #[test]
+    fn scores_guess_without_a_game() {
+        assert_eq!(
+            vec![
+                CharAndStatus('B', CharStatus::NotInWord),
+                CharAndStatus('O', CharStatus::RightPosition),
+                CharAndStatus('O', CharStatus::NotInWord),
+                CharAndStatus('S', CharStatus::WrongPosition),
+                CharAndStatus('T', CharStatus::NotInWord),
+            ],
+            score_guess("SOUND", "BOOST").unwrap()
+        );
+    }
+
+    #[test]
+    fn scoring_words_of_different_lengths_fails() {
+        assert_eq!(
+            Err(GuessError::WrongLength {
+                word: "SOUN".into(),
+                expected: 5,
+                got: 4
+            }),
+            score_guess("SOUND", "SOUN")
+        );
+        // Lengths are compared in characters, not bytes
+        assert!(score_guess("AÑO", "ANO").is_ok());
+    }
+
+    #[test]
+    fn accepts_accented_words_by_character_count() {
+        let words = vec!["cafés".to_string(), "pâtés".to_string()];
+        let dict = VecDictionary::new(words, 5).unwrap();
+        let mut game = WordleGameImpl::new(Box::new(dict), "cafés", 3, false).unwrap();
+
+        if let RoundResult::Continue(status) = game.guess_word("pâtés") {
+            let chars_result = &status.guesses[0].chars_result;
+            assert_eq!(CharAndStatus('Â', CharStatus::NotInWord), chars_result[1]);
+            assert_eq!(
+                CharAndStatus('É', CharStatus::RightPosition),
+                chars_result[3]
+            );
+        } else {
+            unreachable!();
+        }
+        assert!(matches!(
+            game.guess_word("cafe"),
+            RoundResult::Error(GuessError::WrongLength {
+                expected: 5,
+                got: 4,
+                ..
+            })
+        ));
+        assert!(matches!(game.guess_word("cafés"), RoundResult::Won(_, _)));
+    }
+
+    #[test]
+    fn rejects_word_not_in_dictionary() {
+        let dict = EnglishDictionary::new(5).unwrap();
+        assert!(WordleGameImpl::new(Box::new(dict), "xxxxx", 3, false).is_err());
+    }
+
+    #[test]
+    fn letter_status_never_downgrades() {
+        let statuses = [
+            CharStatus::NotUsed,
+            CharStatus::NotInWord,
+            CharStatus::WrongPosition,
+            CharStatus::RightPosition,
+        ];
+        for (i, &first) in statuses.iter().enumerate() {
+            for (j, &second) in statuses.iter().enumerate() {
+                let result = GuessResult {
+                    word: "OO".into(),
+                    chars_result: vec![CharAndStatus('O', first), CharAndStatus('O', second)],
+                };
+                let mut chars_status = HashMap::from([('O', CharStatus::NotUsed)]);
+                WordleGameImpl::merge_chars_status(&mut chars_status, &result);
+                assert_eq!(statuses[i.max(j)], chars_status[&'O']);
+            }
+        }
+    }
+
+    #[test]
+    fn surplus_copies_dont_hide_present_letters() {
+        let words = ["sound", "added", "odder", "dodos"].map(String::from);
+        let dict = VecDictionary::new(words, 5).unwrap();
+        let mut game = WordleGameImpl::new(Box::new(dict), "sound", 6, false).unwrap();
+        let status_of = |game: &WordleGameImpl, c: char| {
+            game.chars_status()
+                .into_iter()
+                .find(|cs| cs.0 == c)
+                .unwrap()
+                .1
+        };
+
+        // The first two Ds are surplus, only the last one is in place
+        if let RoundResult::Continue(status) = game.guess_word("added") {
+            assert_eq!("BBBBG", status.guesses[0].pattern());
+        } else {
+            unreachable!();
+        }
+        assert_eq!(CharStatus::RightPosition, status_of(&game, 'D'));
+
+        // The second O is surplus, as the only O in the word was already found
+        if let RoundResult::Continue(status) = game.guess_word("dodos") {
+            assert_eq!("YGBBY", status.guesses[1].pattern());
+        } else {
+            unreachable!();
+        }
+        assert_eq!(CharStatus::RightPosition, status_of(&game, 'D'));
+        assert_eq!(CharStatus::RightPosition, status_of(&game, 'O'));
+        assert_eq!(CharStatus::WrongPosition, status_of(&game, 'S'));
+    }
+
+    #[test]
+    fn plays_case_sensitive_words() {
+        let words = ["NaCl", "NaBr", "HgCl", "KCl2"].map(String::from);
+        let dict = VecDictionary::new_case_sensitive(words, 4).unwrap();
+        let mut game = WordleGameImpl::new(Box::new(dict), "NaCl", 6, false).unwrap();
+        assert!(matches!(
+            game.guess_word("nacl"),
+            RoundResult::Error(GuessError::InvalidCharacters(_))
+        ));
+        if let RoundResult::Continue(status) = game.guess_word("HgCl") {
+            assert_eq!("BBGG", status.guesses[0].pattern());
+        } else {
+            unreachable!();
+        }
+        assert!(matches!(game.guess_word("NaCl"), RoundResult::Won(_, ref w) if w == "NaCl"));
+    }
+
+    #[test]
+    fn plays_with_emoji() {
+        let symbols = vec!['🍎', '🍌', '🍒', '🍇'];
+        let words = ["🍎🍌🍒", "🍒🍇🍎", "🍇🍇🍇"].map(String::from);
+        let dict = VecDictionary::with_symbols(words, 3, symbols).unwrap();
+        let mut game = WordleGameImpl::new(Box::new(dict), "🍎🍌🍒", 6, false).unwrap();
+        if let RoundResult::Continue(status) = game.guess_word("🍒🍇🍎") {
+            assert_eq!("YBY", status.guesses[0].pattern());
+        } else {
+            unreachable!();
+        }
+        assert!(game
+            .chars_status()
+            .contains(&CharAndStatus('🍇', CharStatus::NotInWord)));
+        assert!(matches!(game.guess_word("🍎🍌🍒"), RoundResult::Won(..)));
+    }
+
+    #[test]
+    fn rejects_repeated_guesses_when_enabled() {
+        let mut game = set_up_game("sound");
+        assert!(matches!(game.guess_word("wrong"), RoundResult::Continue(_)));
+        assert!(matches!(game.guess_word("wrong"), RoundResult::Continue(_)));
+
+        let mut game = set_up_game("sound").with_repeat_guesses_rejected();
+        assert!(matches!(game.guess_word("wrong"), RoundResult::Continue(_)));
+        match game.guess_word("WRONG") {
+            RoundResult::Error(e) => {
+                assert_eq!(GuessError::RepeatedGuess("WRONG".into()), e);
+                assert_eq!("\"WRONG\" was already guessed!", e.to_string());
+            }
+            _ => unreachable!(),
+        }
+        assert_eq!(1, game.guesses_used());
+    }
+
+    #[test]
+    fn owned_results_outlive_the_round() {
+        let mut game = set_up_game("sound");
+        let rounds: Vec<OwnedRoundResult> = ["wrong", "xqzvk", "sound"]
+            .iter()
+            .map(|word| game.guess_word(word).into_owned())
+            .collect();
+
+        match &rounds[0] {
+            OwnedRoundResult::Continue(status) => assert_eq!(1, status.guesses.len()),
+            _ => unreachable!(),
+        }
+        assert!(matches!(rounds[1], OwnedRoundResult::Error(_)));
+        match &rounds[2] {
+            OwnedRoundResult::Won(status, word) => {
+                assert_eq!(2, status.guesses.len());
+                assert_eq!("SOUND", word);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn auto_guess_plays_legal_openers() {
+        let mut game = set_up_game("sound");
+        let result = game.auto_guess("crane").unwrap();
+        assert_eq!("CRANE", result.word());
+        assert_eq!(vec![result], game.status().guesses);
+
+        assert!(matches!(
+            game.auto_guess("xqzvk"),
+            Err(GuessError::NotInDictionary { .. })
+        ));
+        assert_eq!(1, game.guesses_used());
+    }
+
+    #[test]
+    fn answer_is_the_word_to_guess() {
+        let game = set_up_game("sound");
+        assert_eq!("SOUND", game.answer());
+    }
+
+    #[test]
+    fn rejects_zero_guesses() {
+        let dict = EnglishDictionary::new(5).unwrap();
+        assert!(WordleGameImpl::new(Box::new(dict), "sound", 0, false).is_err());
+    }
+
+    #[test]
+    fn unchecked_game_accepts_word_not_in_dictionary() {
+        let dict = EnglishDictionary::new(5).unwrap();
+        let mut game = WordleGameImpl::new_unchecked(Box::new(dict), "xxxxx", 3, false);
+        if let RoundResult::Continue(status) = game.guess_word("boxes") {
+            assert_eq!(
+                CharAndStatus('X', CharStatus::RightPosition),
+                status.guesses[0].chars_result[2]
+            );
+        } else {
+            unreachable!();
+        }
+    }
+
+    #[test]
+    fn lays_out_qwerty_keyboard() {
+        let mut game = set_up_game("sound");
+        game.guess_word("wrong");
+        let rows = game.keyboard_layout(KeyboardLayout::Qwerty);
+        assert_eq!(
+            vec![10, 9, 7],
+            rows.iter().map(|r| r.len()).collect::<Vec<_>>()
+        );
+        assert_eq!(CharAndStatus('Q', CharStatus::NotUsed), rows[0][0]);
+        assert_eq!(CharAndStatus('W', CharStatus::NotInWord), rows[0][1]);
+        assert_eq!(CharAndStatus('N', CharStatus::RightPosition), rows[2][5]);
+    }
+
+    #[test]
+    fn appends_letters_missing_from_layout() {
+        let words = vec!["año".to_string(), "ola".to_string()];
+        let dict = VecDictionary::new(words, 3).unwrap();
+        let game = WordleGameImpl::new(Box::new(dict), "año", 3, false).unwrap();
+        let rows = game.keyboard_layout(KeyboardLayout::Azerty);
+        assert_eq!(4, rows.len());
+        assert_eq!(vec![CharAndStatus('Ñ', CharStatus::NotUsed)], rows[3]);
+        let row: Vec<char> = rows[0].iter().map(|cs| cs.0).collect();
+        assert_eq!(vec!['A', 'O'], row);
+    }
+
+    #[test]
+    fn folds_case_of_turkish_guesses() {
+        let words = vec!["ılık".to_string(), "ıssı".to_string()];
+        let dict = VecDictionary::new(words, 4).unwrap();
+        let mut game = WordleGameImpl::new(Box::new(dict), "ILIK", 3, false).unwrap();
+        assert!(matches!(game.guess_word("ılık"), RoundResult::Won(_, _)));
+    }
 }
@@ -0,0 +1,17 @@
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum KeyboardLayout {
+    Qwerty,
+    Azerty,
+    Dvorak,
+}
+
+impl KeyboardLayout {
+    pub fn rows(&self) -> Vec<Vec<char>> {
+        let rows: &[&str] = match self {
+            KeyboardLayout::Qwerty => &["QWERTYUIOP", "ASDFGHJKL", "ZXCVBNM"],
+            KeyboardLayout::Azerty => &["AZERTYUIOP", "QSDFGHJKLM", "WXCVBN"],
+            KeyboardLayout::Dvorak => &["PYFGCRL", "AOEUIDHTNS", "QJKXBMWVZ"],
+        };
+        rows.iter().map(|row| row.chars().collect()).collect()
+    }
+}
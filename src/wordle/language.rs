@@ -0,0 +1,91 @@
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum Language {
+    English,
+    Spanish,
+    French,
+}
+
+const LATIN_ALPHABET: [char; 26] = [
+    'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S',
+    'T', 'U', 'V', 'W', 'X', 'Y', 'Z',
+];
+
+impl Language {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::Spanish => "es",
+            Language::French => "fr",
+        }
+    }
+
+    // Dictionaries for each language live in dictionaries/<directory>/
+    pub fn directory(&self) -> &'static str {
+        match self {
+            Language::English => "english",
+            Language::Spanish => "spanish",
+            Language::French => "french",
+        }
+    }
+
+    pub fn alphabet(&self) -> Vec<char> {
+        let mut alphabet = LATIN_ALPHABET.to_vec();
+        match self {
+            Language::English => {}
+            Language::Spanish => {
+                // Ñ is a letter of its own, sorted right after N
+                let pos = alphabet.iter().position(|&c| c == 'N').unwrap();
+                alphabet.insert(pos + 1, 'Ñ');
+            }
+            Language::French => alphabet.extend([
+                'À', 'Â', 'Æ', 'Ç', 'É', 'È', 'Ê', 'Ë', 'Î', 'Ï', 'Ô', 'Œ', 'Ù', 'Û', 'Ü', 'Ÿ',
+            ]),
+        }
+        alphabet
+    }
+}
+
+impl FromStr for Language {
+    type Err = String;
+
+    fn from_str(code: &str) -> std::result::Result<Language, String> {
+        match code.to_lowercase().as_str() {
+            "en" => Ok(Language::English),
+            "es" => Ok(Language::Spanish),
+            "fr" => Ok(Language::French),
+            _ => Err(format!(
+                "Unknown language \"{}\" (available: en, es, fr)",
+                code
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_language_codes() {
+        assert_eq!(Ok(Language::Spanish), "es".parse());
+        assert_eq!(Ok(Language::English), "EN".parse());
+        assert!("xx".parse::<Language>().is_err());
+    }
+
+    #[test]
+    fn spanish_alphabet_has_enye_after_n() {
+        let alphabet = Language::Spanish.alphabet();
+        assert_eq!(27, alphabet.len());
+        let pos = alphabet.iter().position(|&c| c == 'N').unwrap();
+        assert_eq!('Ñ', alphabet[pos + 1]);
+    }
+}
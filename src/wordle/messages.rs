@@ -0,0 +1,515 @@
+use super::game::{GuessError, HardModeViolation, HintError};
+use super::language::Language;
+use super::statistics::Statistics;
+
+/// What the game tells the player, in the language of the dictionary being played. English is
+/// the default; the Display of `GuessError`, `HintError` and `Statistics` is always English.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Messages {
+    language: Language,
+}
+
+impl Default for Messages {
+    fn default() -> Messages {
+        Messages::new(Language::English)
+    }
+}
+
+impl Messages {
+    pub fn new(language: Language) -> Messages {
+        Messages { language }
+    }
+
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    pub fn enter_word(&self) -> &'static str {
+        match self.language {
+            Language::English => "Enter a word!: ",
+            Language::Spanish => "¡Ingresá una palabra!: ",
+            Language::French => "Entrez un mot ! : ",
+        }
+    }
+
+    pub fn guesses_left(&self, guesses: usize) -> String {
+        match self.language {
+            Language::English => format!("{} guesses left", guesses),
+            Language::Spanish => format!("Quedan {} intentos", guesses),
+            Language::French => format!("Il reste {} essais", guesses),
+        }
+    }
+
+    pub fn hints_left(&self, hints: usize) -> String {
+        match self.language {
+            Language::English => format!("{} hints left", hints),
+            Language::Spanish => format!("Quedan {} pistas", hints),
+            Language::French => format!("Il reste {} indices", hints),
+        }
+    }
+
+    pub fn moving_on(&self) -> &'static str {
+        match self.language {
+            Language::English => "Moving on...",
+            Language::Spanish => "Seguimos...",
+            Language::French => "On continue...",
+        }
+    }
+
+    pub fn goodbye(&self) -> &'static str {
+        match self.language {
+            Language::English => "Goodbye!",
+            Language::Spanish => "¡Chau!",
+            Language::French => "Au revoir !",
+        }
+    }
+
+    pub fn play_again(&self) -> &'static str {
+        match self.language {
+            Language::English => "Play again?",
+            Language::Spanish => "¿Jugar de nuevo?",
+            Language::French => "Rejouer ?",
+        }
+    }
+
+    pub fn won(&self, word: &str) -> String {
+        match self.language {
+            Language::English => format!("Won! The word was {}", word),
+            Language::Spanish => format!("¡Ganaste! La palabra era {}", word),
+            Language::French => format!("Gagné ! Le mot était {}", word),
+        }
+    }
+
+    pub fn lost(&self, word: &str) -> String {
+        match self.language {
+            Language::English => format!("Lost :( The word was {}", word),
+            Language::Spanish => format!("Perdiste :( La palabra era {}", word),
+            Language::French => format!("Perdu :( Le mot était {}", word),
+        }
+    }
+
+    // For games with several words, already joined by the caller
+    pub fn won_all(&self, words: &str) -> String {
+        match self.language {
+            Language::English => format!("Won! The words were {}", words),
+            Language::Spanish => format!("¡Ganaste! Las palabras eran {}", words),
+            Language::French => format!("Gagné ! Les mots étaient {}", words),
+        }
+    }
+
+    pub fn lost_all(&self, words: &str) -> String {
+        match self.language {
+            Language::English => format!("Lost :( The words were {}", words),
+            Language::Spanish => format!("Perdiste :( Las palabras eran {}", words),
+            Language::French => format!("Perdu :( Les mots étaient {}", words),
+        }
+    }
+
+    pub fn error(&self, error: &str) -> String {
+        match self.language {
+            Language::English => format!("Error: {}", error),
+            Language::Spanish => format!("Error: {}", error),
+            Language::French => format!("Erreur : {}", error),
+        }
+    }
+
+    pub fn available_letters(&self) -> &'static str {
+        match self.language {
+            Language::English => "Available letters: ",
+            Language::Spanish => "Letras disponibles: ",
+            Language::French => "Lettres disponibles : ",
+        }
+    }
+
+    // Positions count from 0, as the game gives them, but are shown counting from 1
+    pub fn hint(&self, position: usize, letter: char) -> String {
+        match self.language {
+            Language::English => format!("Hint: letter {} is {}", position + 1, letter),
+            Language::Spanish => format!("Pista: la letra {} es {}", position + 1, letter),
+            Language::French => format!("Indice : la lettre {} est {}", position + 1, letter),
+        }
+    }
+
+    pub fn mercy_hint(&self, position: usize, letter: char) -> String {
+        match self.language {
+            Language::English => format!("Mercy hint: letter {} is {}", position + 1, letter),
+            Language::Spanish => {
+                format!("Pista de consuelo: la letra {} es {}", position + 1, letter)
+            }
+            Language::French => {
+                format!(
+                    "Indice de secours : la lettre {} est {}",
+                    position + 1,
+                    letter
+                )
+            }
+        }
+    }
+
+    pub fn possible_words(&self, words: usize) -> String {
+        match self.language {
+            Language::English => format!("{} possible words remain", words),
+            Language::Spanish => format!("Quedan {} palabras posibles", words),
+            Language::French => format!("Il reste {} mots possibles", words),
+        }
+    }
+
+    // The error is expected to be localized already, e.g. by guess_error
+    pub fn invalid_opener(&self, opener: &str, error: &str) -> String {
+        match self.language {
+            Language::English => format!("Invalid opener \"{}\": {}", opener, error),
+            Language::Spanish => format!("Primera palabra inválida \"{}\": {}", opener, error),
+            Language::French => format!("Premier mot invalide « {} » : {}", opener, error),
+        }
+    }
+
+    pub fn only_letters(&self, found: &[char]) -> String {
+        let found: Vec<String> = found.iter().map(|c| c.to_string()).collect();
+        match self.language {
+            Language::English => format!("Only letters are allowed, found {}", found.join(" ")),
+            Language::Spanish => {
+                format!("Solo se permiten letras, se encontró {}", found.join(" "))
+            }
+            Language::French => {
+                format!(
+                    "Seules les lettres sont permises, trouvé {}",
+                    found.join(" ")
+                )
+            }
+        }
+    }
+
+    pub fn time(&self, elapsed: &str) -> String {
+        match self.language {
+            Language::English => format!("Time: {}", elapsed),
+            Language::Spanish => format!("Tiempo: {}", elapsed),
+            Language::French => format!("Temps : {}", elapsed),
+        }
+    }
+
+    pub fn score(&self, score: u32) -> String {
+        match self.language {
+            Language::English => format!("Score: {}", score),
+            Language::Spanish => format!("Puntaje: {}", score),
+            Language::French => format!("Score : {}", score),
+        }
+    }
+
+    pub fn resuming_game(&self, path: &str) -> String {
+        match self.language {
+            Language::English => format!("Resuming game saved in {}", path),
+            Language::Spanish => format!("Retomando la partida guardada en {}", path),
+            Language::French => format!("Reprise de la partie sauvegardée dans {}", path),
+        }
+    }
+
+    // Comes before the board's feedback, on the same line; boards are numbered from 1
+    pub fn board(&self, number: usize) -> String {
+        match self.language {
+            Language::English => format!("Board {}: ", number),
+            Language::Spanish => format!("Tablero {}: ", number),
+            Language::French => format!("Grille {} : ", number),
+        }
+    }
+
+    pub fn board_solved(&self) -> &'static str {
+        match self.language {
+            Language::English => "solved",
+            Language::Spanish => "resuelto",
+            Language::French => "résolue",
+        }
+    }
+
+    // The words are already joined by the caller, and may be only the first few of them
+    pub fn candidates(&self, count: usize, words: &str) -> String {
+        match self.language {
+            Language::English => format!("{} possible words: {}", count, words),
+            Language::Spanish => format!("{} palabras posibles: {}", count, words),
+            Language::French => format!("{} mots possibles : {}", count, words),
+        }
+    }
+
+    pub fn suggested_guess(&self, word: &str) -> String {
+        match self.language {
+            Language::English => format!("Suggested guess: {}", word),
+            Language::Spanish => format!("Palabra sugerida: {}", word),
+            Language::French => format!("Mot suggéré : {}", word),
+        }
+    }
+
+    // The feedback letters are the same in every language, since that's what the solver reads
+    pub fn solve_instructions(&self) -> &'static str {
+        match self.language {
+            Language::English => {
+                "Enter each guess and its feedback, e.g. \"crane BBYGB\" (G green, Y yellow, B gray)"
+            }
+            Language::Spanish => {
+                "Ingresá cada intento y su resultado, p. ej. \"perro BBYGB\" (G verde, Y amarillo, B gris)"
+            }
+            Language::French => {
+                "Entrez chaque essai et son résultat, p. ex. « crane BBYGB » (G vert, Y jaune, B gris)"
+            }
+        }
+    }
+
+    pub fn enter_feedback(&self) -> &'static str {
+        match self.language {
+            Language::English => "Guess and feedback: ",
+            Language::Spanish => "Intento y resultado: ",
+            Language::French => "Essai et résultat : ",
+        }
+    }
+
+    pub fn feedback_expected(&self) -> &'static str {
+        match self.language {
+            Language::English => "Expected a guess and its feedback, e.g. \"crane BBYGB\"",
+            Language::Spanish => "Se esperaba un intento y su resultado, p. ej. \"perro BBYGB\"",
+            Language::French => "Un essai et son résultat étaient attendus, p. ex. « crane BBYGB »",
+        }
+    }
+
+    pub fn solved(&self) -> &'static str {
+        match self.language {
+            Language::English => "Solved!",
+            Language::Spanish => "¡Resuelto!",
+            Language::French => "Résolu !",
+        }
+    }
+
+    pub fn no_word_fits(&self) -> &'static str {
+        match self.language {
+            Language::English => "No word fits that feedback, check that it was typed right",
+            Language::Spanish => "Ninguna palabra da ese resultado, revisá que esté bien escrito",
+            Language::French => "Aucun mot ne donne ce résultat, vérifiez qu'il est bien saisi",
+        }
+    }
+
+    pub fn word_must_be(&self, word: &str) -> String {
+        match self.language {
+            Language::English => format!("The word must be {}", word),
+            Language::Spanish => format!("La palabra tiene que ser {}", word),
+            Language::French => format!("Le mot doit être {}", word),
+        }
+    }
+
+    pub fn no_games_played(&self) -> &'static str {
+        match self.language {
+            Language::English => "No games played yet",
+            Language::Spanish => "Todavía no se jugó ninguna partida",
+            Language::French => "Aucune partie jouée pour l'instant",
+        }
+    }
+
+    pub fn statistics(&self, stats: &Statistics) -> String {
+        if self.language == Language::English {
+            return stats.to_string();
+        }
+        let spanish = self.language == Language::Spanish;
+        let mut lines = if spanish {
+            vec![
+                format!("Partidas: {}", stats.games_played),
+                format!("% de victorias: {:.0}", stats.win_percentage()),
+                format!("Racha actual: {}", stats.current_streak),
+                format!("Racha máxima: {}", stats.max_streak),
+            ]
+        } else {
+            vec![
+                format!("Parties : {}", stats.games_played),
+                format!("% de victoires : {:.0}", stats.win_percentage()),
+                format!("Série actuelle : {}", stats.current_streak),
+                format!("Meilleure série : {}", stats.max_streak),
+            ]
+        };
+        if stats.hints_used > 0 {
+            lines.push(if spanish {
+                format!("Pistas usadas: {}", stats.hints_used)
+            } else {
+                format!("Indices utilisés : {}", stats.hints_used)
+            });
+        }
+        lines.push(if spanish {
+            "Distribución de intentos:".into()
+        } else {
+            "Répartition des essais :".into()
+        });
+        lines.extend(stats.distribution_rows());
+        lines.iter().map(|line| format!("{}\n", line)).collect()
+    }
+
+    pub fn hint_error(&self, error: &HintError) -> String {
+        match (self.language, error) {
+            (Language::English, _) => error.to_string(),
+            (Language::Spanish, HintError::NoHintsLeft) => "No quedan pistas".into(),
+            (Language::Spanish, HintError::AllLettersKnown) => {
+                "Ya se conocen todas las letras".into()
+            }
+            (Language::Spanish, HintError::Unavailable) => {
+                "No hay pistas en este modo de juego".into()
+            }
+            (Language::Spanish, HintError::GameAlreadyOver) => "¡El juego ya terminó!".into(),
+            (Language::French, HintError::NoHintsLeft) => "Il ne reste plus d'indices".into(),
+            (Language::French, HintError::AllLettersKnown) => {
+                "Toutes les lettres sont déjà connues".into()
+            }
+            (Language::French, HintError::Unavailable) => {
+                "Les indices ne sont pas disponibles dans ce mode".into()
+            }
+            (Language::French, HintError::GameAlreadyOver) => {
+                "La partie est déjà terminée !".into()
+            }
+        }
+    }
+
+    pub fn guess_error(&self, error: &GuessError) -> String {
+        if self.language == Language::English {
+            return error.to_string();
+        }
+        let spanish = self.language == Language::Spanish;
+        match error {
+            GuessError::WrongLength {
+                word,
+                expected,
+                got,
+            } => {
+                if spanish {
+                    format!(
+                        "\"{}\" tiene {} letras, se necesitan {}",
+                        word, got, expected
+                    )
+                } else {
+                    format!("« {} » a {} lettres, il en faut {}", word, got, expected)
+                }
+            }
+            GuessError::NotInDictionary { word, suggestion } => {
+                let mut message = if spanish {
+                    format!("¡\"{}\" no está en el diccionario!", word)
+                } else {
+                    format!("« {} » n'est pas dans le dictionnaire !", word)
+                };
+                if let Some(suggestion) = suggestion {
+                    if spanish {
+                        message += &format!(" ¿Quisiste decir \"{}\"?", suggestion);
+                    } else {
+                        message += &format!(" Vouliez-vous dire « {} » ?", suggestion);
+                    }
+                }
+                message
+            }
+            GuessError::HardModeViolation(HardModeViolation::MissingLetterAt {
+                position,
+                letter,
+            }) => {
+                if spanish {
+                    format!("La {}ª letra tiene que ser {}", position + 1, letter)
+                } else if *position == 0 {
+                    format!("La 1re lettre doit être {}", letter)
+                } else {
+                    format!("La {}e lettre doit être {}", position + 1, letter)
+                }
+            }
+            GuessError::HardModeViolation(HardModeViolation::MissingLetter(letter)) => {
+                if spanish {
+                    format!("La palabra tiene que contener {}", letter)
+                } else {
+                    format!("Le mot doit contenir {}", letter)
+                }
+            }
+            GuessError::InvalidCharacters(chars) => {
+                let chars: Vec<String> = chars.iter().map(|c| c.to_string()).collect();
+                if spanish {
+                    format!("La palabra no puede tener {}", chars.join(", "))
+                } else {
+                    format!("Le mot ne peut pas contenir {}", chars.join(", "))
+                }
+            }
+            GuessError::RepeatedGuess(word) => {
+                if spanish {
+                    format!("¡\"{}\" ya fue intentada!", word)
+                } else {
+                    format!("« {} » a déjà été essayé !", word)
+                }
+            }
+            GuessError::GameAlreadyOver => {
+                if spanish {
+                    "¡El juego ya terminó!".into()
+                } else {
+                    "La partie est déjà terminée !".into()
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn english_is_the_default() {
+        let messages = Messages::default();
+        assert_eq!("Won! The word was SOUND", messages.won("SOUND"));
+        let error = GuessError::WrongLength {
+            word: "SOUN".into(),
+            expected: 5,
+            got: 4,
+        };
+        assert_eq!(error.to_string(), messages.guess_error(&error));
+    }
+
+    #[test]
+    fn localizes_messages() {
+        let messages = Messages::new(Language::Spanish);
+        assert_eq!("Perdiste :( La palabra era ÑANDÚ", messages.lost("ÑANDÚ"));
+        let error = GuessError::WrongLength {
+            word: "SUEÑ".into(),
+            expected: 5,
+            got: 4,
+        };
+        assert_eq!(
+            "\"SUEÑ\" tiene 4 letras, se necesitan 5",
+            messages.guess_error(&error)
+        );
+
+        assert_eq!("Pista: la letra 2 es Ñ", messages.hint(1, 'Ñ'));
+        let error = GuessError::HardModeViolation(HardModeViolation::MissingLetterAt {
+            position: 3,
+            letter: 'N',
+        });
+        assert_eq!("La 4ª letra tiene que ser N", messages.guess_error(&error));
+
+        let messages = Messages::new(Language::French);
+        assert_eq!("Il reste 3 essais", messages.guesses_left(3));
+        assert_eq!(
+            "Seules les lettres sont permises, trouvé ’ !",
+            messages.only_letters(&['’', '!'])
+        );
+        let error = GuessError::HardModeViolation(HardModeViolation::MissingLetter('O'));
+        assert_eq!("Le mot doit contenir O", messages.guess_error(&error));
+        assert_eq!(
+            "La partie est déjà terminée !",
+            messages.guess_error(&GuessError::GameAlreadyOver)
+        );
+        assert_eq!(
+            "Il ne reste plus d'indices",
+            messages.hint_error(&HintError::NoHintsLeft)
+        );
+    }
+
+    #[test]
+    fn localizes_statistics() {
+        let stats = Statistics {
+            games_played: 2,
+            games_won: 1,
+            current_streak: 0,
+            max_streak: 1,
+            distribution: vec![0, 1],
+            hints_used: 0,
+        };
+        assert_eq!(stats.to_string(), Messages::default().statistics(&stats));
+
+        let spanish = Messages::new(Language::Spanish).statistics(&stats);
+        assert!(spanish.starts_with("Partidas: 2\n% de victorias: 50\n"));
+        assert!(spanish.contains("Distribución de intentos:\n1 | 0\n2 | "));
+        assert!(!spanish.contains("Pistas"));
+    }
+}
@@ -1,7 +1,40 @@
+mod absurdle;
+mod builder;
+mod case;
+mod clock;
+mod config;
+mod constraints;
+mod daily;
 mod dictionary;
 mod errors;
 mod game;
+mod keyboard;
+mod language;
+mod messages;
+mod multiboard;
+mod palette;
+mod rotation;
+pub mod solver;
+mod statistics;
+#[cfg(feature = "wasm")]
+mod wasm;
 
+pub use absurdle::*;
+pub use builder::*;
+pub use case::*;
+pub use clock::*;
+pub use config::*;
+pub use constraints::*;
+pub use daily::*;
 pub use dictionary::*;
 pub use errors::*;
 pub use game::*;
+pub use keyboard::*;
+pub use language::*;
+pub use messages::*;
+pub use multiboard::*;
+pub use palette::*;
+pub use rotation::*;
+pub use statistics::*;
+#[cfg(feature = "wasm")]
+pub use wasm::*;
@@ -0,0 +1,187 @@
+use super::errors::Result;
+use super::game::{GuessError, GuessResult, RoundResult, WordleGame, WordleGameImpl};
+
+/// Several boards played at once, Dordle/Quordle style: every guess goes to all the boards that
+/// aren't solved yet, and the game is won once all of them are, within a shared guess budget.
+pub struct MultiBoardGame {
+    boards: Vec<WordleGameImpl>,
+}
+
+impl MultiBoardGame {
+    // The boards' own guess limit is the shared budget, so they must all agree on it
+    pub fn new(boards: Vec<WordleGameImpl>) -> Result<MultiBoardGame> {
+        let max_guesses = boards
+            .first()
+            .ok_or("At least one board is required")?
+            .max_guesses();
+        if boards
+            .iter()
+            .any(|board| board.max_guesses() != max_guesses)
+        {
+            return Err("All boards must allow the same number of guesses".into());
+        }
+        Ok(MultiBoardGame { boards })
+    }
+
+    // The usual budget: one guess per board plus five to spare, e.g. 9 guesses for 4 boards
+    pub fn default_max_guesses(num_boards: usize) -> usize {
+        num_boards + 5
+    }
+
+    pub fn boards(&self) -> &[WordleGameImpl] {
+        &self.boards
+    }
+
+    pub fn max_guesses(&self) -> Option<usize> {
+        self.boards[0].max_guesses()
+    }
+
+    // Boards still being played have seen every guess, so they're the ones to ask
+    pub fn guesses_used(&self) -> usize {
+        self.boards
+            .iter()
+            .map(|board| board.guesses_used())
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn guesses_remaining(&self) -> Option<usize> {
+        self.max_guesses()
+            .map(|max| max.saturating_sub(self.guesses_used()))
+    }
+
+    pub fn is_won(&self) -> bool {
+        self.boards.iter().all(is_solved)
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.boards.iter().all(|board| board.outcome().is_some())
+    }
+
+    /// Applies the guess to every unsolved board. Each entry is that board's feedback, or None if
+    /// the board was already solved. A guess any unsolved board rejects (e.g. because of its hard
+    /// mode) isn't played on any of them, so it doesn't count.
+    pub fn guess_word(
+        &mut self,
+        word: &str,
+    ) -> std::result::Result<Vec<Option<GuessResult>>, GuessError> {
+        if self.is_over() {
+            return Err(GuessError::GameAlreadyOver);
+        }
+
+        // Every board is checked first, so none of them is left with a guess the others rejected
+        for board in self.boards.iter().filter(|board| !is_solved(board)) {
+            board.check_guess(word)?;
+        }
+
+        let mut results = Vec::new();
+        for board in self.boards.iter_mut() {
+            if is_solved(board) {
+                results.push(None);
+                continue;
+            }
+            match board.guess_word(word) {
+                RoundResult::Error(e) => return Err(e),
+                RoundResult::Won(status, _)
+                | RoundResult::Lost(status, _)
+                | RoundResult::Continue(status) => {
+                    results.push(status.guesses.last().cloned());
+                }
+            }
+        }
+        Ok(results)
+    }
+}
+
+fn is_solved(board: &WordleGameImpl) -> bool {
+    board.outcome().is_some_and(|outcome| outcome.won)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::dictionary::VecDictionary;
+    use super::*;
+
+    fn set_up_game(words: &[&str], max_guesses: usize) -> MultiBoardGame {
+        let boards = words
+            .iter()
+            .map(|word| {
+                let words = vec!["sound".into(), "wrong".into(), "boost".into()];
+                let dict = VecDictionary::new(words, 5).unwrap();
+                WordleGameImpl::new(Box::new(dict), word, max_guesses, false).unwrap()
+            })
+            .collect();
+        MultiBoardGame::new(boards).unwrap()
+    }
+
+    #[test]
+    fn wins_once_every_board_is_solved() {
+        let mut game = set_up_game(&["sound", "boost"], 3);
+
+        let results = game.guess_word("sound").unwrap();
+        assert!(results[0].as_ref().unwrap().is_won());
+        assert!(!results[1].as_ref().unwrap().is_won());
+        assert!(!game.is_won());
+
+        // The solved board doesn't take any more guesses
+        let results = game.guess_word("boost").unwrap();
+        assert!(results[0].is_none());
+        assert!(results[1].as_ref().unwrap().is_won());
+        assert!(game.is_won() && game.is_over());
+        assert_eq!(
+            (2, Some(1)),
+            (game.guesses_used(), game.guesses_remaining())
+        );
+        assert_eq!(Err(GuessError::GameAlreadyOver), game.guess_word("wrong"));
+    }
+
+    #[test]
+    fn loses_when_the_budget_runs_out() {
+        let mut game = set_up_game(&["sound", "boost"], 2);
+        game.guess_word("sound").unwrap();
+        game.guess_word("wrong").unwrap();
+        assert!(game.is_over());
+        assert!(!game.is_won());
+        assert_eq!(Some(0), game.guesses_remaining());
+    }
+
+    #[test]
+    fn rejected_guesses_are_not_counted() {
+        let mut game = set_up_game(&["sound", "boost"], 3);
+        assert!(matches!(
+            game.guess_word("bogus"),
+            Err(GuessError::NotInDictionary { .. })
+        ));
+        assert_eq!(0, game.guesses_used());
+    }
+
+    #[test]
+    fn guess_rejected_by_one_board_is_not_played_on_any() {
+        let boards = ["sound", "boost"]
+            .iter()
+            .map(|word| {
+                let words = vec!["sound".into(), "wrong".into(), "boost".into()];
+                let dict = VecDictionary::new(words, 5).unwrap();
+                WordleGameImpl::new(Box::new(dict), word, 3, true).unwrap()
+            })
+            .collect();
+        let mut game = MultiBoardGame::new(boards).unwrap();
+        game.guess_word("wrong").unwrap();
+
+        // SOUND would solve the first board, but the second one needs the O found in the middle
+        assert!(matches!(
+            game.guess_word("sound"),
+            Err(GuessError::HardModeViolation(_))
+        ));
+        assert_eq!(1, game.guesses_used());
+        assert!(game.boards().iter().all(|board| board.outcome().is_none()));
+    }
+
+    #[test]
+    fn boards_must_share_the_budget() {
+        let mut boards = set_up_game(&["sound"], 3).boards;
+        boards.extend(set_up_game(&["boost"], 4).boards);
+        assert!(MultiBoardGame::new(boards).is_err());
+        assert!(MultiBoardGame::new(Vec::new()).is_err());
+    }
+}
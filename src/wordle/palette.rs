@@ -0,0 +1,101 @@
+use super::game::CharStatus;
+
+use colored::Color;
+use std::str::FromStr;
+
+// Background colors used to show the status of each letter
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Palette {
+    pub not_in_word: Color,
+    pub wrong_position: Color,
+    pub right_position: Color,
+}
+
+impl Palette {
+    pub fn standard() -> Palette {
+        Palette {
+            not_in_word: Color::Red,
+            wrong_position: Color::Yellow,
+            right_position: Color::Green,
+        }
+    }
+
+    // The high contrast scheme of the original game
+    pub fn colorblind() -> Palette {
+        Palette {
+            not_in_word: Color::TrueColor {
+                r: 0x78,
+                g: 0x7c,
+                b: 0x7e,
+            },
+            wrong_position: Color::TrueColor {
+                r: 0x85,
+                g: 0xc0,
+                b: 0xf9,
+            },
+            right_position: Color::TrueColor {
+                r: 0xf5,
+                g: 0x79,
+                b: 0x3a,
+            },
+        }
+    }
+
+    // Letters that haven't been used yet have no background
+    pub fn background(&self, status: CharStatus) -> Option<Color> {
+        match status {
+            CharStatus::NotInWord => Some(self.not_in_word),
+            CharStatus::WrongPosition => Some(self.wrong_position),
+            CharStatus::RightPosition => Some(self.right_position),
+            CharStatus::NotUsed => None,
+        }
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Palette {
+        Palette::standard()
+    }
+}
+
+impl FromStr for Palette {
+    type Err = String;
+
+    fn from_str(name: &str) -> std::result::Result<Palette, String> {
+        match name.to_lowercase().as_str() {
+            "standard" => Ok(Palette::standard()),
+            "colorblind" => Ok(Palette::colorblind()),
+            _ => Err(format!(
+                "Unknown palette \"{}\" (available: standard, colorblind)",
+                name
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_palette_names() {
+        assert_eq!(Ok(Palette::colorblind()), "colorblind".parse());
+        assert_eq!(Ok(Palette::standard()), "Standard".parse());
+        assert!("rainbow".parse::<Palette>().is_err());
+    }
+
+    #[test]
+    fn colorblind_palette_avoids_red_and_green() {
+        let palette = Palette::colorblind();
+        for status in [
+            CharStatus::NotInWord,
+            CharStatus::WrongPosition,
+            CharStatus::RightPosition,
+        ] {
+            let color = palette.background(status).unwrap();
+            assert_ne!(Color::Red, color);
+            assert_ne!(Color::Green, color);
+        }
+        assert_eq!(None, palette.background(CharStatus::NotUsed));
+    }
+}
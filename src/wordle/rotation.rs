@@ -0,0 +1,207 @@
+use super::case::normalize_word;
+use super::dictionary::Dictionary;
+use super::errors::{Result, ResultExt};
+
+use chrono::NaiveDate;
+use std::cell::Cell;
+use std::fs;
+use std::path::PathBuf;
+
+/// Serves the answers of a fixed, ordered list one after the other, like numbered daily puzzles
+/// starting on `start`. Guesses are still checked against the wrapped dictionary.
+///
+/// `get_random_word` hands out the next answer not served yet, and fails once all of them have
+/// been. The position can be kept in a state file so the sequence carries on across runs.
+/// `word_for_date` gives the puzzle of a given day, starting over from the first answer once the
+/// list runs out. `get_random_word_seeded` is a plain deterministic pick like in any other
+/// dictionary, so `daily_word` doesn't follow the rotation's dates; use `word_for_date` for that.
+pub struct RotationDictionary {
+    dictionary: Box<dyn Dictionary>,
+    answers: Vec<String>,
+    start: NaiveDate,
+    next: Cell<usize>,
+    state_file: Option<PathBuf>,
+}
+
+impl RotationDictionary {
+    pub fn new(
+        dictionary: Box<dyn Dictionary>,
+        answers: Vec<String>,
+        start: NaiveDate,
+    ) -> Result<RotationDictionary> {
+        let answers: Vec<String> = answers
+            .iter()
+            .map(|word| normalize_word(word, dictionary.case_sensitive()))
+            .collect();
+        if answers.is_empty() {
+            return Err("The rotation needs at least one answer".into());
+        }
+        if let Some(word) = answers.iter().find(|word| !dictionary.contains_word(word)) {
+            return Err(format!("Answer \"{}\" is not in the dictionary", word).into());
+        }
+        Ok(RotationDictionary {
+            dictionary,
+            answers,
+            start,
+            next: Cell::new(0),
+            state_file: None,
+        })
+    }
+
+    // Resumes from the position saved in the file, if it exists, and saves it there from now on
+    pub fn with_state_file(mut self, path: PathBuf) -> Result<RotationDictionary> {
+        if path.exists() {
+            let state = fs::read_to_string(&path)?;
+            let next = state
+                .trim()
+                .parse()
+                .chain_err(|| format!("Invalid rotation state in {}", path.display()))?;
+            self.next.set(next);
+        }
+        self.state_file = Some(path);
+        Ok(self)
+    }
+
+    // How many answers were served so far
+    pub fn served(&self) -> usize {
+        self.next.get()
+    }
+
+    // Puzzles are numbered from 0, the one on the start date
+    pub fn puzzle_number(&self, date: NaiveDate) -> Result<usize> {
+        let days = (date - self.start).num_days();
+        usize::try_from(days)
+            .map_err(|_| format!("There are no puzzles before {}", self.start).into())
+    }
+
+    pub fn word_for_date(&self, date: NaiveDate) -> Result<String> {
+        let number = self.puzzle_number(date)?;
+        Ok(self.answers[number % self.answers.len()].clone())
+    }
+
+    fn check_size(&self, size: usize) -> Result<()> {
+        match self.dictionary.word_size() {
+            Some(word_size) if word_size != size => Err(format!(
+                "Tried to get a word of {} characters using a dictionary of {} characters",
+                size, word_size
+            )
+            .into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Dictionary for RotationDictionary {
+    fn get_random_word(&self, size: usize) -> Result<String> {
+        self.check_size(size)?;
+        let next = self.next.get();
+        let word = self.answers.get(next).ok_or_else(|| {
+            format!(
+                "All {} answers in the rotation have been used",
+                self.answers.len()
+            )
+        })?;
+
+        if let Some(path) = &self.state_file {
+            fs::write(path, (next + 1).to_string())
+                .chain_err(|| format!("Couldn't save the rotation state to {}", path.display()))?;
+        }
+        self.next.set(next + 1);
+        Ok(word.clone())
+    }
+
+    fn get_random_word_seeded(&self, size: usize, seed: u64) -> Result<String> {
+        self.check_size(size)?;
+        let index = (seed % self.answers.len() as u64) as usize;
+        Ok(self.answers[index].clone())
+    }
+
+    fn contains_word(&self, word: &str) -> bool {
+        self.dictionary.contains_word(word)
+    }
+
+    fn available_chars(&self) -> Vec<char> {
+        self.dictionary.available_chars()
+    }
+
+    fn words(&self) -> Box<dyn Iterator<Item = &str> + '_> {
+        self.dictionary.words()
+    }
+
+    fn word_size(&self) -> Option<usize> {
+        self.dictionary.word_size()
+    }
+
+    fn case_sensitive(&self) -> bool {
+        self.dictionary.case_sensitive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::dictionary::VecDictionary;
+    use super::*;
+
+    fn set_up_rotation() -> RotationDictionary {
+        let words = ["sound", "wrong", "boost", "bound"].map(String::from);
+        let dict = VecDictionary::new(words, 5).unwrap();
+        let answers = vec!["wrong".into(), "sound".into(), "boost".into()];
+        let start = NaiveDate::from_ymd_opt(2021, 6, 19).unwrap();
+        RotationDictionary::new(Box::new(dict), answers, start).unwrap()
+    }
+
+    #[test]
+    fn serves_answers_in_order_until_exhausted() {
+        let rotation = set_up_rotation();
+        assert_eq!("WRONG", rotation.get_random_word(5).unwrap());
+        assert_eq!("SOUND", rotation.get_random_word(5).unwrap());
+        assert_eq!("BOOST", rotation.get_random_word(5).unwrap());
+        assert_eq!(3, rotation.served());
+        assert!(rotation.get_random_word(5).is_err());
+        assert!(rotation.contains_word("bound"));
+    }
+
+    #[test]
+    fn keeps_position_in_state_file() {
+        let path = std::env::temp_dir().join(format!("wordle-{}-rotation", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let rotation = set_up_rotation().with_state_file(path.clone()).unwrap();
+        assert_eq!("WRONG", rotation.get_random_word(5).unwrap());
+
+        let rotation = set_up_rotation().with_state_file(path.clone()).unwrap();
+        assert_eq!(1, rotation.served());
+        assert_eq!("SOUND", rotation.get_random_word(5).unwrap());
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn numbers_puzzles_by_date_and_wraps_around() {
+        let rotation = set_up_rotation();
+        let date = |day| NaiveDate::from_ymd_opt(2021, 6, day).unwrap();
+        assert_eq!(0, rotation.puzzle_number(date(19)).unwrap());
+        assert!(rotation.puzzle_number(date(18)).is_err());
+
+        assert_eq!("SOUND", rotation.word_for_date(date(20)).unwrap());
+        assert_eq!("WRONG", rotation.word_for_date(date(22)).unwrap());
+    }
+
+    #[test]
+    fn seeded_picks_take_any_seed() {
+        let rotation = set_up_rotation();
+        assert_eq!("WRONG", rotation.get_random_word_seeded(5, 42).unwrap());
+        assert_eq!("SOUND", rotation.get_random_word_seeded(5, 1).unwrap());
+        assert!(rotation.get_random_word_seeded(5, u64::MAX).is_ok());
+        assert!(rotation.get_random_word_seeded(4, 42).is_err());
+        // Seeded picks don't serve answers from the sequence
+        assert_eq!(0, rotation.served());
+    }
+
+    #[test]
+    fn rejects_answers_not_in_dictionary() {
+        let dict = VecDictionary::new(["sound".to_string()], 5).unwrap();
+        let start = NaiveDate::from_ymd_opt(2021, 6, 19).unwrap();
+        let answers = vec!["wrong".into()];
+        assert!(RotationDictionary::new(Box::new(dict), answers, start).is_err());
+    }
+}
@@ -0,0 +1,383 @@
+use super::case::normalize_word;
+use super::constraints::Constraints;
+use super::dictionary::Dictionary;
+use super::game::{CharAndStatus, CharStatus, GuessResult, WordleGameImpl};
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+pub struct Solver {
+    dictionary: Box<dyn Dictionary>,
+}
+
+impl Solver {
+    pub fn new(dictionary: Box<dyn Dictionary>) -> Solver {
+        Solver { dictionary }
+    }
+
+    /// Returns the dictionary words that are still consistent with all the given guesses, sorted
+    /// alphabetically.
+    pub fn candidates(&self, guesses: &[GuessResult]) -> Vec<String> {
+        let mut candidates: Vec<String> = self
+            .dictionary
+            .words()
+            .filter(|word| {
+                guesses
+                    .iter()
+                    .all(|guess| Solver::is_consistent(word, guess))
+            })
+            .map(|word| word.into())
+            .collect();
+        candidates.sort();
+        candidates
+    }
+
+    /// The dictionary words allowed by the constraints that start with what has been typed so far,
+    /// sorted alphabetically, e.g. to autocomplete a guess. The prefix is case folded like guesses.
+    pub fn filter_words(&self, constraints: &Constraints, prefix: &str) -> Vec<String> {
+        let prefix = normalize_word(prefix, self.dictionary.case_sensitive());
+        let mut words: Vec<String> = self
+            .dictionary
+            .words()
+            .filter(|word| word.starts_with(&prefix) && constraints.allows(word))
+            .map(|word| word.into())
+            .collect();
+        words.sort();
+        words
+    }
+
+    /// Suggests the candidate whose distinct letters are the most common among all the remaining
+    /// candidates. Ties are broken alphabetically.
+    pub fn best_guess(&self, guesses: &[GuessResult]) -> Option<String> {
+        let candidates = self.candidates(guesses);
+        let frequencies = Solver::letter_frequencies(&candidates);
+
+        let score = |word: &str| -> usize {
+            word.chars()
+                .collect::<HashSet<char>>()
+                .iter()
+                .map(|c| frequencies[c])
+                .sum()
+        };
+
+        candidates
+            .iter()
+            .max_by(|a, b| score(a).cmp(&score(b)).then_with(|| b.cmp(a)))
+            .cloned()
+    }
+
+    /// Counts how many of the candidates contain each letter, however many times it appears in them.
+    pub fn letter_frequencies(candidates: &[String]) -> BTreeMap<char, usize> {
+        let mut frequencies = BTreeMap::new();
+        for word in candidates.iter() {
+            for c in word.chars().collect::<HashSet<char>>() {
+                *frequencies.entry(c).or_default() += 1;
+            }
+        }
+        frequencies
+    }
+
+    /// Counts, for every position, how many of the candidates have each letter there.
+    pub fn positional_letter_frequencies(candidates: &[String]) -> Vec<BTreeMap<char, usize>> {
+        let mut frequencies: Vec<BTreeMap<char, usize>> = Vec::new();
+        for word in candidates.iter() {
+            for (pos, c) in word.chars().enumerate() {
+                if frequencies.len() <= pos {
+                    frequencies.resize(pos + 1, BTreeMap::new());
+                }
+                *frequencies[pos].entry(c).or_default() += 1;
+            }
+        }
+        frequencies
+    }
+
+    /// Ranks every candidate, taken as a guess, by the expected information (in bits) it would
+    /// reveal: the Shannon entropy of how its feedback patterns split the candidates. The result
+    /// is sorted by descending entropy, ties broken alphabetically.
+    pub fn rank_by_entropy(&self, candidates: &[String]) -> Vec<(String, f64)> {
+        let mut ranking: Vec<(String, f64)> = candidates
+            .iter()
+            .map(|guess| (guess.clone(), Solver::entropy(guess, candidates)))
+            .collect();
+        ranking.sort_by(|(word_a, a), (word_b, b)| b.total_cmp(a).then_with(|| word_a.cmp(word_b)));
+        ranking
+    }
+
+    /// The number of candidates left in the worst case after playing `guess`: the size of the
+    /// largest group of candidates that would all give it the same feedback. Minimizing it is the
+    /// minimax strategy.
+    pub fn worst_case_bucket(&self, guess: &str, candidates: &[String]) -> usize {
+        Solver::buckets(guess, candidates)
+            .into_values()
+            .max()
+            .unwrap_or(0)
+    }
+
+    // How many candidates give each feedback pattern to the guess
+    fn buckets(guess: &str, candidates: &[String]) -> HashMap<Vec<CharStatus>, usize> {
+        let mut buckets: HashMap<Vec<CharStatus>, usize> = HashMap::new();
+        for target in candidates.iter() {
+            if let Ok(result) = WordleGameImpl::guess_result(target, guess) {
+                let pattern = result.chars_result.iter().map(|cs| cs.1).collect();
+                *buckets.entry(pattern).or_default() += 1;
+            }
+        }
+        buckets
+    }
+
+    fn entropy(guess: &str, candidates: &[String]) -> f64 {
+        let total = candidates.len() as f64;
+        Solver::buckets(guess, candidates)
+            .values()
+            .map(|&count| {
+                let p = count as f64 / total;
+                -p * p.log2()
+            })
+            .sum()
+    }
+
+    /// A word is consistent with a guess if, had it been the target word, the guess would have
+    /// produced exactly the same feedback. Scoring through the game itself keeps the subtle
+    /// duplicate-letter rules in one place.
+    fn is_consistent(word: &str, guess: &GuessResult) -> bool {
+        match WordleGameImpl::guess_result(word, guess.word()) {
+            Ok(result) => result
+                .chars_result
+                .iter()
+                .zip(guess.chars_result.iter())
+                .all(|(CharAndStatus(_, a), CharAndStatus(_, b))| a == b),
+            Err(_) => false,
+        }
+    }
+}
+
+/// Limits for ranking openers, since trying every word against every other one takes time
+/// quadratic in the size of the dictionary.
+#[derive(Debug, Clone, Default)]
+pub struct OpenerSearch {
+    // Only try this many words, evenly spread over the dictionary, both as guesses and as targets
+    pub sample: Option<usize>,
+    // Stop trying more guesses after this long, ranking the ones tried so far
+    pub time_limit: Option<Duration>,
+}
+
+// Every step-th word, so a sample still covers the whole alphabet
+fn sample_words(words: Vec<String>, sample: Option<usize>) -> Vec<String> {
+    match sample {
+        Some(size) if size > 0 && size < words.len() => {
+            let step = words.len() as f64 / size as f64;
+            (0..size)
+                .map(|i| words[(i as f64 * step) as usize].clone())
+                .collect()
+        }
+        _ => words,
+    }
+}
+
+/// Ranks the dictionary's words as a first guess by the entropy of their feedback over the whole
+/// dictionary, like `rank_by_entropy` does for candidates. `progress` is called after each guess
+/// tried with how many were tried so far and how many there are in total.
+pub fn rank_openers(
+    dictionary: &dyn Dictionary,
+    search: &OpenerSearch,
+    mut progress: impl FnMut(usize, usize),
+) -> Vec<(String, f64)> {
+    let words: Vec<String> = dictionary.words().map(|w| w.into()).collect();
+    let words = sample_words(words, search.sample);
+
+    let start = Instant::now();
+    let mut ranking = Vec::new();
+    for guess in words.iter() {
+        if search
+            .time_limit
+            .is_some_and(|limit| start.elapsed() >= limit)
+        {
+            break;
+        }
+        ranking.push((guess.clone(), Solver::entropy(guess, &words)));
+        progress(ranking.len(), words.len());
+    }
+    ranking.sort_by(|(word_a, a), (word_b, b)| b.total_cmp(a).then_with(|| word_a.cmp(word_b)));
+    ranking
+}
+
+/// The best first guess for the dictionary, trying every word with no limits. It's slow for big
+/// dictionaries, so the result is kept for as long as the program runs and only worked out once
+/// per word list.
+pub fn precompute_best_opener(dictionary: &dyn Dictionary) -> Option<String> {
+    static OPENERS: OnceLock<Mutex<HashMap<u64, String>>> = OnceLock::new();
+
+    let mut hasher = DefaultHasher::new();
+    for word in dictionary.words() {
+        word.hash(&mut hasher);
+    }
+    let key = hasher.finish();
+
+    let openers = OPENERS.get_or_init(|| Mutex::new(HashMap::new()));
+    if let Some(opener) = openers.lock().unwrap().get(&key) {
+        return Some(opener.clone());
+    }
+    let (opener, _) = rank_openers(dictionary, &OpenerSearch::default(), |_, _| {})
+        .into_iter()
+        .next()?;
+    openers.lock().unwrap().insert(key, opener.clone());
+    Some(opener)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::dictionary::{EnglishDictionary, VecDictionary};
+    use super::*;
+
+    fn set_up_solver() -> Solver {
+        let dict = EnglishDictionary::new(5).unwrap();
+        Solver::new(Box::new(dict))
+    }
+
+    #[test]
+    fn candidates_are_consistent_with_feedback() {
+        let solver = set_up_solver();
+        let guesses = vec![WordleGameImpl::guess_result("SOUND", "WRONG").unwrap()];
+        let candidates = solver.candidates(&guesses);
+
+        assert!(candidates.contains(&"SOUND".to_string()));
+        assert!(!candidates.contains(&"WRONG".to_string()));
+        for word in candidates.iter() {
+            assert_eq!(Some('N'), word.chars().nth(3));
+            assert_ne!(Some('O'), word.chars().nth(2));
+            assert!(word.contains('O'));
+            assert!(!word.contains('W') && !word.contains('R') && !word.contains('G'));
+        }
+    }
+
+    #[test]
+    fn candidates_narrow_down_to_target() {
+        let solver = set_up_solver();
+        let guesses = vec![
+            WordleGameImpl::guess_result("SOUND", "WRONG").unwrap(),
+            WordleGameImpl::guess_result("SOUND", "BOOST").unwrap(),
+            WordleGameImpl::guess_result("SOUND", "SOUND").unwrap(),
+        ];
+        assert_eq!(vec!["SOUND".to_string()], solver.candidates(&guesses));
+    }
+
+    #[test]
+    fn filters_words_by_constraints_and_prefix() {
+        let solver = set_up_solver();
+        let guesses = vec![WordleGameImpl::guess_result("SOUND", "WRONG").unwrap()];
+        let constraints = Constraints::from_guesses(&guesses);
+
+        let words = solver.filter_words(&constraints, "so");
+        assert!(words.contains(&"SOUND".to_string()));
+        for word in words.iter() {
+            assert!(word.starts_with("SO"));
+            assert!(constraints.allows(word));
+        }
+
+        let all = solver.filter_words(&constraints, "");
+        assert_eq!(solver.candidates(&guesses), all);
+        assert!(solver.filter_words(&constraints, "wr").is_empty());
+    }
+
+    #[test]
+    fn ranks_openers_over_the_whole_dictionary() {
+        let words = ["bound", "found", "sound", "wrong", "boost"].map(String::from);
+        let dict = VecDictionary::new(words, 5).unwrap();
+
+        let mut calls = Vec::new();
+        let ranking = rank_openers(&dict, &OpenerSearch::default(), |done, total| {
+            calls.push((done, total))
+        });
+        assert_eq!(5, ranking.len());
+        assert_eq!((5, 5), *calls.last().unwrap());
+        assert!(ranking.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+        assert_eq!(Some(ranking[0].0.clone()), precompute_best_opener(&dict));
+
+        let search = OpenerSearch {
+            sample: Some(2),
+            time_limit: None,
+        };
+        let ranking = rank_openers(&dict, &search, |_, _| {});
+        let sampled: Vec<&str> = ranking.iter().map(|(w, _)| w.as_str()).collect();
+        assert_eq!(2, sampled.len());
+        assert!(sampled.contains(&"BOOST") && sampled.contains(&"FOUND"));
+
+        let search = OpenerSearch {
+            sample: None,
+            time_limit: Some(Duration::ZERO),
+        };
+        assert!(rank_openers(&dict, &search, |_, _| {}).is_empty());
+    }
+
+    #[test]
+    fn best_guess_is_a_candidate() {
+        let solver = set_up_solver();
+        let guesses = vec![WordleGameImpl::guess_result("SOUND", "WRONG").unwrap()];
+        let best = solver.best_guess(&guesses).unwrap();
+        assert!(solver.candidates(&guesses).contains(&best));
+    }
+
+    #[test]
+    fn counts_letter_frequencies() {
+        let candidates: Vec<String> = vec!["BOOST".into(), "SOUND".into()];
+
+        let frequencies = Solver::letter_frequencies(&candidates);
+        // BOOST has two Os, but it's still one word containing O
+        assert_eq!(Some(&2), frequencies.get(&'O'));
+        assert_eq!(Some(&2), frequencies.get(&'S'));
+        assert_eq!(Some(&1), frequencies.get(&'B'));
+        assert_eq!(
+            vec!['B', 'D', 'N', 'O', 'S', 'T', 'U'],
+            frequencies.keys().copied().collect::<Vec<char>>()
+        );
+
+        let positional = Solver::positional_letter_frequencies(&candidates);
+        assert_eq!(5, positional.len());
+        assert_eq!(BTreeMap::from([('O', 2)]), positional[1]);
+        assert_eq!(BTreeMap::from([('O', 1), ('U', 1)]), positional[2]);
+    }
+
+    #[test]
+    fn ranks_by_entropy() {
+        let solver = set_up_solver();
+        let candidates: Vec<String> = vec!["BOUND", "FOUND", "HOUND", "MOUND", "ROUND", "SOUND"]
+            .into_iter()
+            .map(|w| w.into())
+            .collect();
+        let ranking = solver.rank_by_entropy(&candidates);
+
+        // Every guess tells exactly one candidate apart from the other five
+        assert_eq!(candidates.len(), ranking.len());
+        let expected = -(1.0 / 6.0) * (1.0f64 / 6.0).log2() - (5.0 / 6.0) * (5.0f64 / 6.0).log2();
+        for (_, entropy) in ranking.iter() {
+            assert!((entropy - expected).abs() < 1e-9);
+        }
+        assert_eq!("BOUND", ranking[0].0);
+    }
+
+    #[test]
+    fn computes_worst_case_bucket() {
+        let solver = set_up_solver();
+        let candidates: Vec<String> = vec!["BOUND", "FOUND", "HOUND", "MOUND", "SOUND"]
+            .into_iter()
+            .map(|w| w.into())
+            .collect();
+
+        // Any of the candidates only tells itself apart from the other four
+        assert_eq!(4, solver.worst_case_bucket("SOUND", &candidates));
+        // FRESH tells F, H and S apart, leaving BOUND and MOUND together
+        assert_eq!(2, solver.worst_case_bucket("FRESH", &candidates));
+        assert_eq!(0, solver.worst_case_bucket("SOUND", &[]));
+    }
+
+    #[test]
+    fn ranking_is_sorted_by_descending_entropy() {
+        let solver = set_up_solver();
+        let guesses = vec![WordleGameImpl::guess_result("SOUND", "WRONG").unwrap()];
+        let ranking = solver.rank_by_entropy(&solver.candidates(&guesses));
+        assert!(ranking.windows(2).all(|w| w[0].1 >= w[1].1));
+    }
+}
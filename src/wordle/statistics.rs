@@ -0,0 +1,211 @@
+use super::game::RoundResult;
+
+#[cfg(feature = "serde")]
+use super::errors::Result;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+#[cfg(feature = "serde")]
+use std::fs;
+#[cfg(feature = "serde")]
+use std::path::Path;
+
+#[derive(Debug, PartialEq, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Statistics {
+    pub games_played: u32,
+    pub games_won: u32,
+    pub current_streak: u32,
+    pub max_streak: u32,
+    // distribution[n] is the number of games won with n + 1 guesses
+    pub distribution: Vec<u32>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub hints_used: u32,
+}
+
+impl Statistics {
+    pub fn new() -> Statistics {
+        Statistics::default()
+    }
+
+    // Only finished games are recorded, any other round result is ignored
+    pub fn record(&mut self, result: &RoundResult) {
+        match result {
+            RoundResult::Won(status, _) => {
+                self.games_played += 1;
+                self.hints_used += status.hints.len() as u32;
+                self.games_won += 1;
+                self.current_streak += 1;
+                self.max_streak = self.max_streak.max(self.current_streak);
+
+                let num_guesses = status.guesses.len();
+                if self.distribution.len() < num_guesses {
+                    self.distribution.resize(num_guesses, 0);
+                }
+                self.distribution[num_guesses - 1] += 1;
+            }
+            RoundResult::Lost(status, _) => {
+                self.games_played += 1;
+                self.hints_used += status.hints.len() as u32;
+                self.current_streak = 0;
+            }
+            RoundResult::Continue(_) | RoundResult::Error(_) => {}
+        }
+    }
+
+    pub fn win_percentage(&self) -> f64 {
+        if self.games_played == 0 {
+            0.0
+        } else {
+            100.0 * self.games_won as f64 / self.games_played as f64
+        }
+    }
+
+    // A missing file means no games were played yet
+    #[cfg(feature = "serde")]
+    pub fn load(path: &Path) -> Result<Statistics> {
+        if !path.exists() {
+            return Ok(Statistics::new());
+        }
+        let json = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, serde_json::to_string(self)?)?;
+        Ok(())
+    }
+
+    // The guess distribution as a bar chart, one row per number of guesses. It has no labels, so
+    // it reads the same in any language.
+    pub(crate) fn distribution_rows(&self) -> Vec<String> {
+        const MAX_BAR_WIDTH: u32 = 20;
+
+        let most_frequent = self.distribution.iter().copied().max().unwrap_or(0);
+        let rows = self.distribution.len().max(6);
+        (0..rows)
+            .map(|n| {
+                let count = self.distribution.get(n).copied().unwrap_or(0);
+                let width = if most_frequent == 0 {
+                    0
+                } else {
+                    // Any non-zero count gets at least one mark
+                    (count * MAX_BAR_WIDTH).div_ceil(most_frequent)
+                };
+                let bar = "#".repeat(width as usize);
+                if bar.is_empty() {
+                    format!("{} | {}", n + 1, count)
+                } else {
+                    format!("{} | {} {}", n + 1, bar, count)
+                }
+            })
+            .collect()
+    }
+}
+
+// In English; `Messages::statistics` shows them in other languages
+impl fmt::Display for Statistics {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Played: {}", self.games_played)?;
+        writeln!(f, "Win %: {:.0}", self.win_percentage())?;
+        writeln!(f, "Current streak: {}", self.current_streak)?;
+        writeln!(f, "Max streak: {}", self.max_streak)?;
+        if self.hints_used > 0 {
+            writeln!(f, "Hints used: {}", self.hints_used)?;
+        }
+        writeln!(f, "Guess distribution:")?;
+        for row in self.distribution_rows() {
+            writeln!(f, "{}", row)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::dictionary::EnglishDictionary;
+    use super::super::game::{WordleGame, WordleGameImpl};
+    use super::*;
+
+    fn play(stats: &mut Statistics, guesses: &[&str]) {
+        let dict = EnglishDictionary::new(5).unwrap();
+        let mut game = WordleGameImpl::new(Box::new(dict), "sound", 3, false).unwrap();
+        for guess in guesses {
+            let result = game.guess_word(guess);
+            stats.record(&result);
+        }
+    }
+
+    #[test]
+    fn records_wins_and_losses() {
+        let mut stats = Statistics::new();
+        play(&mut stats, &["wrong", "sound"]);
+        play(&mut stats, &["sound"]);
+        assert_eq!(
+            (2, 2, 2, 2),
+            (
+                stats.games_played,
+                stats.games_won,
+                stats.current_streak,
+                stats.max_streak
+            )
+        );
+        assert_eq!(vec![1, 1], stats.distribution);
+
+        play(&mut stats, &["wrong", "wrong", "wrong"]);
+        assert_eq!(
+            (3, 2, 0, 2),
+            (
+                stats.games_played,
+                stats.games_won,
+                stats.current_streak,
+                stats.max_streak
+            )
+        );
+        assert_eq!(67, stats.win_percentage().round() as u32);
+    }
+
+    #[test]
+    fn records_hints_used() {
+        let mut stats = Statistics::new();
+        let dict = EnglishDictionary::new(5).unwrap();
+        let mut game = WordleGameImpl::new(Box::new(dict), "sound", 3, false)
+            .unwrap()
+            .with_hints(2);
+        game.use_hint().unwrap();
+        stats.record(&game.guess_word("sound"));
+        assert_eq!(1, stats.hints_used);
+        assert!(stats.to_string().contains("Hints used: 1\n"));
+    }
+
+    #[test]
+    fn displays_histogram() {
+        let mut stats = Statistics::new();
+        play(&mut stats, &["wrong", "sound"]);
+        play(&mut stats, &["wrong", "sound"]);
+        play(&mut stats, &["sound"]);
+        let display = stats.to_string();
+        assert!(display.contains("Played: 3\n"));
+        assert!(display.contains("Win %: 100\n"));
+        assert!(display.contains(&format!("1 | {} 1\n", "#".repeat(10))));
+        assert!(display.contains(&format!("2 | {} 2\n", "#".repeat(20))));
+        assert!(display.contains("6 | 0\n"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn saves_and_loads_statistics() {
+        let path = std::env::temp_dir().join(format!("wordle-{}-stats.json", std::process::id()));
+        assert_eq!(Statistics::new(), Statistics::load(&path).unwrap());
+
+        let mut stats = Statistics::new();
+        play(&mut stats, &["wrong", "sound"]);
+        stats.save(&path).unwrap();
+        assert_eq!(stats, Statistics::load(&path).unwrap());
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,129 @@
+use super::dictionary::VecDictionary;
+use super::game::{GuessResult, RoundResult, WordleGame, WordleGameImpl};
+
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+#[derive(Serialize)]
+#[serde(tag = "state", rename_all = "lowercase")]
+enum RoundJson<'a> {
+    Error {
+        message: String,
+    },
+    Won {
+        guess: &'a GuessResult,
+        word: String,
+    },
+    Lost {
+        guess: &'a GuessResult,
+        word: String,
+    },
+    Continue {
+        guess: &'a GuessResult,
+    },
+}
+
+fn round_json(result: RoundResult) -> String {
+    let json = match result {
+        RoundResult::Error(e) => RoundJson::Error {
+            message: e.to_string(),
+        },
+        RoundResult::Won(status, word) => RoundJson::Won {
+            guess: status.guesses.last().unwrap(),
+            word,
+        },
+        RoundResult::Lost(status, word) => RoundJson::Lost {
+            guess: status.guesses.last().unwrap(),
+            word,
+        },
+        RoundResult::Continue(status) => RoundJson::Continue {
+            guess: status.guesses.last().unwrap(),
+        },
+    };
+    serde_json::to_string(&json).unwrap()
+}
+
+/// A game for JavaScript callers. There is no filesystem in the browser, so the dictionary is
+/// passed in as a list of words:
+///
+/// ```js
+/// import { WasmGame } from "wordle";
+///
+/// const game = new WasmGame(["sound", "wrong", "boost"], "sound", 6, false);
+/// const round = JSON.parse(game.guess("wrong"));
+/// // { state: "continue", guess: { word: "WRONG", chars_result: [...] } }
+/// ```
+#[wasm_bindgen]
+pub struct WasmGame {
+    game: WordleGameImpl,
+}
+
+#[wasm_bindgen]
+impl WasmGame {
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        words: Vec<String>,
+        word: &str,
+        max_guesses: usize,
+        hard_mode: bool,
+    ) -> std::result::Result<WasmGame, JsError> {
+        let dictionary = VecDictionary::new(words, word.chars().count())
+            .map_err(|e| JsError::new(&e.to_string()))?;
+        let game = WordleGameImpl::new(Box::new(dictionary), word, max_guesses, hard_mode)
+            .map_err(|e| JsError::new(&e.to_string()))?;
+        Ok(WasmGame { game })
+    }
+
+    // Returns the outcome of the round as JSON
+    pub fn guess(&mut self, word: &str) -> String {
+        round_json(self.game.guess_word(word))
+    }
+
+    #[wasm_bindgen(js_name = guessesRemaining)]
+    pub fn guesses_remaining(&self) -> Option<usize> {
+        self.game.guesses_remaining()
+    }
+
+    // The status of every letter of the alphabet as JSON
+    #[wasm_bindgen(js_name = charsStatus)]
+    pub fn chars_status(&self) -> String {
+        serde_json::to_string(&self.game.chars_status()).unwrap()
+    }
+
+    // The same, one character per letter; see WordleGame::keyboard_state
+    #[wasm_bindgen(js_name = keyboardState)]
+    pub fn keyboard_state(&self) -> String {
+        self.game.keyboard_state()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set_up_game() -> WasmGame {
+        let words = vec!["sound".into(), "wrong".into(), "boost".into()];
+        WasmGame::new(words, "sound", 2, false).unwrap()
+    }
+
+    #[test]
+    fn reports_rounds_as_json() {
+        let mut game = set_up_game();
+        let round = game.guess("wrong");
+        assert!(round.starts_with(r#"{"state":"continue","guess":{"word":"WRONG""#));
+        assert_eq!(Some(1), game.guesses_remaining());
+
+        let round = game.guess("sound");
+        assert!(round.starts_with(r#"{"state":"won""#));
+        assert!(round.ends_with(r#""word":"SOUND"}"#));
+    }
+
+    #[test]
+    fn reports_errors_as_json() {
+        let mut game = set_up_game();
+        assert_eq!(
+            r#"{"state":"error","message":"Word \"DDDDD\" is not in the dictionary!"}"#,
+            game.guess("ddddd")
+        );
+    }
+}